@@ -0,0 +1,81 @@
+#!/usr/bin/env python3
+"""Generate src/wdotool_lib/keysyms_table.rs from the X11 keysym headers
+
+The headers are the ones xkbcommon-keysyms.h is generated from, and the names follow the
+same rules: XK_a is "a", XF86XK_AudioMute is "XF86AudioMute", SunXK_Copy is "SunCopy"...
+
+usage: scripts/generate_keysyms.py [/usr/include/X11]
+"""
+
+import re
+import sys
+from pathlib import Path
+
+HEADERS = [
+    "keysymdef.h",
+    "XF86keysym.h",
+    "Sunkeysym.h",
+    "DECkeysym.h",
+    "HPkeysym.h",
+]
+
+DEFINE = re.compile(
+    r"^#define\s+(\w*?)XK_(\w+)\s+"
+    r"(?:0x([0-9a-fA-F]+)|_EVDEVK\(0x([0-9a-fA-F]+)\))"
+    r"\s*(?:/\*\s*(?:\(?U\+([0-9A-F]{4,6}))?.*)?$"
+)
+
+# keysyms whose value isn't their code point, and that have no U+ comment
+EXTRA_CHARS = {
+    0xFF09: 0x09,  # Tab
+    0xFF0D: 0x0A,  # Return
+}
+
+
+def is_direct(keysym: int) -> bool:
+    """Keysyms converted by keysym_to_char without the table"""
+    return 0x20 <= keysym <= 0x7E or 0xA0 <= keysym <= 0xFF or 0x1000100 <= keysym <= 0x110FFFF
+
+
+def main() -> None:
+    include = Path(sys.argv[1] if len(sys.argv) > 1 else "/usr/include/X11")
+    names: dict[str, int] = {}
+    chars: dict[int, int] = dict(EXTRA_CHARS)
+    for header in HEADERS:
+        for line in (include / header).read_text().splitlines():
+            match = DEFINE.match(line)
+            if match is None:
+                continue
+            prefix, name, value, evdev, code_point = match.groups()
+            keysym = int(value, 16) if value else 0x10081000 + int(evdev, 16)
+            # the first definition of a name wins, the next ones are legacy duplicates
+            names.setdefault(prefix + name, keysym)
+            if code_point and not is_direct(keysym):
+                chars.setdefault(keysym, int(code_point, 16))
+
+    out = [
+        "// @generated by scripts/generate_keysyms.py from the X11 keysym headers,",
+        "// do not edit",
+        "",
+        "/// Keysym names and values, sorted by name",
+        "const KEYSYMS: &[(&str, u32)] = &[",
+    ]
+    for name in sorted(names, key=lambda name: name.encode()):
+        out.append(f'    ("{name}", 0x{names[name]:04x}),')
+    out += [
+        "];",
+        "",
+        "/// Characters of the keysyms that are neither Latin 1 nor unicode keysyms, sorted by",
+        "/// keysym",
+        "const KEYSYM_CHARS: &[(u32, char)] = &[",
+    ]
+    for keysym in sorted(chars):
+        out.append(f"    (0x{keysym:04x}, '\\u{{{chars[keysym]:04x}}}'),")
+    out.append("];")
+
+    target = Path(__file__).parent.parent / "src" / "wdotool_lib" / "keysyms_table.rs"
+    target.write_text("\n".join(out) + "\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -1,8 +1,170 @@
 pub mod wdotool_lib;
 
-use numpy::PyArray3;
-use pyo3::prelude::*;
-use wdotool_lib::UIntValue;
+use std::time::Duration;
+
+use numpy::{PyArray3, PyArrayMethods, PyUntypedArrayMethods};
+use pyo3::{
+    create_exception,
+    exceptions::{PyRuntimeError, PyTimeoutError, PyTypeError, PyValueError},
+    prelude::*,
+    types::PyDict,
+};
+use wayland_client::protocol::{wl_output::Transform, wl_shm::Format};
+use wdotool_lib::{
+    image::ChannelOrder, FloatValue, KeyValue, MouseButton, Region, RetryableError, ScrollMode,
+    UIntValue,
+};
+
+create_exception!(
+    wdotool,
+    ScreencopyFailed,
+    PyRuntimeError,
+    "The compositor failed to copy the frame, the screenshot can be retried"
+);
+
+/// Raise TimeoutError and ScreencopyFailed for the errors that can be retried
+fn py_error(err: anyhow::Error) -> anyhow::Error {
+    match err.downcast_ref::<RetryableError>() {
+        Some(RetryableError::Timeout(_)) => PyTimeoutError::new_err(err.to_string()).into(),
+        Some(RetryableError::ScreencopyFailed) => ScreencopyFailed::new_err(err.to_string()).into(),
+        None => err,
+    }
+}
+
+/// A key is either a raw evdev keycode or a chord string like "ctrl+shift+t"
+fn key_value(key: &Bound<'_, PyAny>) -> PyResult<KeyValue> {
+    match key.extract::<u32>() {
+        Ok(code) => Ok(KeyValue::Code(code)),
+        Err(_) => Ok(KeyValue::Chord(key.extract()?)),
+    }
+}
+
+/// A button is either a name like "left" or "back", or a raw BTN_* evdev code
+fn mouse_button(button: &Bound<'_, PyAny>) -> anyhow::Result<MouseButton> {
+    match button.extract::<u32>() {
+        Ok(code) => Ok(MouseButton::Code(code)),
+        Err(_) => button.extract::<String>()?.parse(),
+    }
+}
+
+fn transform_name(transform: Transform) -> &'static str {
+    match transform {
+        Transform::_90 => "90",
+        Transform::_180 => "180",
+        Transform::_270 => "270",
+        Transform::Flipped => "flipped",
+        Transform::Flipped90 => "flipped-90",
+        Transform::Flipped180 => "flipped-180",
+        Transform::Flipped270 => "flipped-270",
+        _ => "normal",
+    }
+}
+
+fn format_name(format: Format) -> &'static str {
+    match format {
+        Format::Xrgb8888 => "xrgb8888",
+        Format::Argb8888 => "argb8888",
+        Format::Xbgr8888 => "xbgr8888",
+        Format::Abgr8888 => "abgr8888",
+        Format::Bgr888 => "bgr888",
+        Format::Rgb888 => "rgb888",
+        _ => "unknown",
+    }
+}
+
+/// A capture whose pixels are shared with the compositor's shm buffer, without copy
+#[pyclass]
+struct Frame {
+    internal: wdotool_lib::Frame,
+}
+
+#[pymethods]
+impl Frame {
+    /// Read-only view of shape (height, width, bytes per pixel) over the shm buffer, in
+    /// the byte order of `format` (e.g. B, G, R, X for xrgb8888)
+    #[getter]
+    fn pixels<'py>(this: Bound<'py, Self>) -> PyResult<Bound<'py, PyArray3<u8>>> {
+        let frame = this.borrow();
+        let pixels = frame.internal.pixels();
+        // SAFETY: the mapping is owned by the frame, and is neither unmapped nor written
+        // to by another capture while the frame is alive
+        let array = unsafe { PyArray3::borrow_from_array_bound(&pixels, this.clone().into_any()) };
+        // the buffer is mapped read-only
+        array.call_method1("setflags", (false,))?;
+        Ok(array)
+    }
+
+    /// shm format of the pixels, e.g. "xrgb8888"
+    #[getter]
+    fn format(&self) -> &'static str {
+        format_name(self.internal.format)
+    }
+
+    /// Presentation time of the content in seconds, with an arbitrary offset (wlroots
+    /// compositors use the clock of time.monotonic)
+    #[getter]
+    fn timestamp(&self) -> f64 {
+        self.internal.timestamp.as_secs_f64()
+    }
+
+    /// Rectangles (x, y, width, height) that changed since the previous capture with
+    /// damage of the output, or None for captures without damage tracking
+    #[getter]
+    fn damage(&self) -> Option<Vec<(i32, i32, i32, i32)>> {
+        self.internal.damage.as_ref().map(|damage| {
+            damage
+                .iter()
+                .map(|region| (region.x, region.y, region.width, region.height))
+                .collect()
+        })
+    }
+
+    #[getter]
+    fn output_name(&self) -> Option<&str> {
+        self.internal.output_name.as_deref()
+    }
+
+    #[getter]
+    fn width(&self) -> u32 {
+        self.internal.width
+    }
+
+    #[getter]
+    fn height(&self) -> u32 {
+        self.internal.height
+    }
+
+    /// Bytes between the start of two rows in the shm buffer
+    #[getter]
+    fn stride(&self) -> u32 {
+        self.internal.stride
+    }
+
+    /// True if the compositor stored the rows bottom to top; `pixels` is already flipped
+    #[getter]
+    fn y_invert(&self) -> bool {
+        self.internal.y_invert
+    }
+
+    /// Scale of the output at the time of the capture
+    #[getter]
+    fn scale(&self) -> i32 {
+        self.internal.scale
+    }
+
+    /// Transform of the output at the time of the capture, e.g. "normal" or "90"
+    #[getter]
+    fn transform(&self) -> &'static str {
+        transform_name(self.internal.transform)
+    }
+
+    /// Copy of the pixels in the given channel order
+    #[pyo3(signature = (order="BGRA"))]
+    fn to_array(&self, order: &str) -> anyhow::Result<Py<PyArray3<u8>>> {
+        let pixels = self.internal.convert(order.parse()?)?;
+        Python::with_gil(|py| Ok(PyArray3::from_owned_array_bound(py, pixels).unbind()))
+    }
+}
 
 #[pyclass]
 struct Wdotool {
@@ -12,22 +174,26 @@ struct Wdotool {
 #[pymethods]
 impl Wdotool {
     #[new]
-    #[pyo3(signature = (wayland_display=None))]
-    pub fn new(wayland_display: Option<&str>) -> anyhow::Result<Self> {
-        let mut internal = wdotool_lib::Wdotool::connect(wayland_display)?;
-        internal.wait_ouput_detected()?;
+    #[pyo3(signature = (wayland_display=None, timeout_ms=5000))]
+    pub fn new(wayland_display: Option<&str>, timeout_ms: u64) -> anyhow::Result<Self> {
+        let timeout = Duration::from_millis(timeout_ms);
+        let mut internal = wdotool_lib::Wdotool::connect_with_timeout(wayland_display, timeout)
+            .map_err(py_error)?;
+        internal.wait_ouput_detected().map_err(py_error)?;
         Ok(Wdotool { internal })
     }
 
-    #[pyo3(signature = (x_extent, y_extent, x, y, x_max=None, y_max=None))]
+    #[pyo3(signature = (x, y, *, x_extent=None, y_extent=None, x_max=None, y_max=None, screen_name=None))]
+    #[allow(clippy::too_many_arguments)]
     pub fn move_mouse(
         &mut self,
-        x_extent: u32,
-        y_extent: u32,
         x: u32,
         y: u32,
+        x_extent: Option<u32>,
+        y_extent: Option<u32>,
         x_max: Option<u32>,
         y_max: Option<u32>,
+        screen_name: Option<&str>,
     ) -> anyhow::Result<()> {
         let x = match x_max {
             Some(x_max) => UIntValue::UIntRange(x, x_max),
@@ -38,10 +204,72 @@ impl Wdotool {
             None => UIntValue::UInt(y),
         };
 
-        self.internal.move_mouse(x, y, x_extent, y_extent)?;
+        self.internal
+            .move_mouse(x, y, x_extent, y_extent, screen_name)
+            .map_err(py_error)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (button, duration_ms, duration_ms_max=None))]
+    pub fn click(
+        &mut self,
+        button: &Bound<'_, PyAny>,
+        duration_ms: u32,
+        duration_ms_max: Option<u32>,
+    ) -> anyhow::Result<()> {
+        let button = mouse_button(button)?;
+        let duration_ms = match duration_ms_max {
+            Some(duration_ms_max) => UIntValue::UIntRange(duration_ms, duration_ms_max),
+            None => UIntValue::UInt(duration_ms),
+        };
+
+        self.internal.click(button, duration_ms).map_err(py_error)?;
         Ok(())
     }
 
+    #[pyo3(signature = (dx, dy, dx_max=None, dy_max=None))]
+    pub fn move_mouse_relative(
+        &mut self,
+        dx: f64,
+        dy: f64,
+        dx_max: Option<f64>,
+        dy_max: Option<f64>,
+    ) -> anyhow::Result<()> {
+        let dx = match dx_max {
+            Some(dx_max) => FloatValue::FloatRange(dx, dx_max),
+            None => FloatValue::Float(dx),
+        };
+        let dy = match dy_max {
+            Some(dy_max) => FloatValue::FloatRange(dy, dy_max),
+            None => FloatValue::Float(dy),
+        };
+
+        self.internal
+            .move_mouse_relative(dx, dy)
+            .map_err(py_error)?;
+        Ok(())
+    }
+
+    /// Return the last position of the mouse as a dict with x, y, x_extent, y_extent and
+    /// screen_name, or None if it was never moved to an absolute position
+    ///
+    /// The coordinates are in a space of size x_extent by y_extent, relative to the output
+    /// screen_name, or to all the outputs when it is None.
+    pub fn get_mouse_position(&self) -> PyResult<Option<Py<PyDict>>> {
+        let Some(position) = self.internal.get_mouse_position() else {
+            return Ok(None);
+        };
+        Python::with_gil(|py| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("x", position.x)?;
+            dict.set_item("y", position.y)?;
+            dict.set_item("x_extent", position.x_extent)?;
+            dict.set_item("y_extent", position.y_extent)?;
+            dict.set_item("screen_name", position.screen_name)?;
+            Ok(Some(dict.unbind()))
+        })
+    }
+
     #[pyo3(signature = (duration_ms, duration_ms_max=None))]
     pub fn left_click(
         &mut self,
@@ -53,7 +281,7 @@ impl Wdotool {
             None => UIntValue::UInt(duration_ms),
         };
 
-        self.internal.left_click(duration_ms)?;
+        self.internal.left_click(duration_ms).map_err(py_error)?;
         Ok(())
     }
 
@@ -68,35 +296,282 @@ impl Wdotool {
             None => UIntValue::UInt(duration_ms),
         };
 
-        self.internal.right_click(duration_ms)?;
+        self.internal.right_click(duration_ms).map_err(py_error)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (dx, dy, mode="wheel"))]
+    pub fn scroll(&mut self, dx: f64, dy: f64, mode: &str) -> anyhow::Result<()> {
+        let mode: ScrollMode = mode.parse()?;
+        self.internal.scroll(dx, dy, mode).map_err(py_error)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (dx, dy, duration_ms, duration_ms_max=None))]
+    pub fn kinetic_scroll(
+        &mut self,
+        dx: f64,
+        dy: f64,
+        duration_ms: u32,
+        duration_ms_max: Option<u32>,
+    ) -> anyhow::Result<()> {
+        let duration_ms = match duration_ms_max {
+            Some(duration_ms_max) => UIntValue::UIntRange(duration_ms, duration_ms_max),
+            None => UIntValue::UInt(duration_ms),
+        };
+
+        self.internal
+            .kinetic_scroll(dx, dy, duration_ms)
+            .map_err(py_error)?;
         Ok(())
     }
 
     #[pyo3(signature = (key, duration_ms, duration_ms_max=None))]
     pub fn key_press(
         &mut self,
-        key: u32,
+        key: &Bound<'_, PyAny>,
         duration_ms: u32,
         duration_ms_max: Option<u32>,
     ) -> anyhow::Result<()> {
+        let key = key_value(key)?;
         let duration_ms = match duration_ms_max {
             Some(duration_ms_max) => UIntValue::UIntRange(duration_ms, duration_ms_max),
             None => UIntValue::UInt(duration_ms),
         };
 
-        self.internal.key_press(key, duration_ms)?;
+        self.internal
+            .key_press(key, duration_ms)
+            .map_err(py_error)?;
         Ok(())
     }
 
-    #[pyo3(signature = (screen_name=None))]
-    pub fn screenshot(&mut self, screen_name: Option<&str>) -> anyhow::Result<Py<PyArray3<u8>>> {
-        let screenshot = self.internal.screenshot(screen_name)?;
+    #[pyo3(signature = (key))]
+    pub fn key_down(&mut self, key: &Bound<'_, PyAny>) -> anyhow::Result<()> {
+        self.internal.key_down(key_value(key)?).map_err(py_error)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (key))]
+    pub fn key_up(&mut self, key: &Bound<'_, PyAny>) -> anyhow::Result<()> {
+        self.internal.key_up(key_value(key)?).map_err(py_error)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (button))]
+    pub fn button_down(&mut self, button: &Bound<'_, PyAny>) -> anyhow::Result<()> {
+        self.internal
+            .button_down(mouse_button(button)?)
+            .map_err(py_error)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (button))]
+    pub fn button_up(&mut self, button: &Bound<'_, PyAny>) -> anyhow::Result<()> {
+        self.internal
+            .button_up(mouse_button(button)?)
+            .map_err(py_error)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (text, duration_ms, duration_ms_max=None))]
+    pub fn type_text(
+        &mut self,
+        text: &str,
+        duration_ms: u32,
+        duration_ms_max: Option<u32>,
+    ) -> anyhow::Result<()> {
+        let duration_ms = match duration_ms_max {
+            Some(duration_ms_max) => UIntValue::UIntRange(duration_ms, duration_ms_max),
+            None => UIntValue::UInt(duration_ms),
+        };
+
+        self.internal
+            .type_text(text, duration_ms)
+            .map_err(py_error)?;
+        Ok(())
+    }
+
+    /// Return a counter incremented each time an output is added, removed or changed
+    pub fn outputs_generation(&mut self) -> anyhow::Result<u64> {
+        self.internal.outputs_generation().map_err(py_error)
+    }
+
+    /// Return the metadata of every output, as a list of dicts
+    pub fn outputs(&mut self) -> anyhow::Result<Vec<Py<PyDict>>> {
+        self.internal.refresh_outputs().map_err(py_error)?;
+        Python::with_gil(|py| {
+            let mut outputs = Vec::new();
+            for output in self.internal.outputs() {
+                let dict = PyDict::new_bound(py);
+                dict.set_item("name", &output.name)?;
+                dict.set_item("description", &output.description)?;
+                dict.set_item("make", &output.make)?;
+                dict.set_item("model", &output.model)?;
+                dict.set_item(
+                    "physical_size",
+                    (output.physical_width, output.physical_height),
+                )?;
+                dict.set_item(
+                    "mode",
+                    output.mode.as_ref().map(|mode| (mode.width, mode.height)),
+                )?;
+                dict.set_item(
+                    "refresh",
+                    output.mode.as_ref().map(|mode| mode.refresh as f64 / 1000.),
+                )?;
+                dict.set_item("scale", output.scale)?;
+                dict.set_item("transform", transform_name(output.transform))?;
+                dict.set_item("position", output.logical_position())?;
+                dict.set_item("logical_size", output.logical_size())?;
+                outputs.push(dict.unbind());
+            }
+            Ok(outputs)
+        })
+    }
+
+    #[pyo3(signature = (screen_name=None, region=None, include_cursor=false, order="BGRA"))]
+    pub fn screenshot(
+        &mut self,
+        screen_name: Option<&str>,
+        region: Option<(i32, i32, i32, i32)>,
+        include_cursor: bool,
+        order: &str,
+    ) -> anyhow::Result<Py<PyArray3<u8>>> {
+        let order: ChannelOrder = order.parse()?;
+        let region = region.map(Region::from);
+        // converting straight from the shm buffer saves a copy
+        let frame = self
+            .internal
+            .capture(screen_name, region, include_cursor)
+            .map_err(py_error)?;
+        let pixels = frame.convert(order)?;
 
         Python::with_gil(|py| {
-            let a = PyArray3::from_owned_array_bound(py, screenshot).unbind();
+            let a = PyArray3::from_owned_array_bound(py, pixels).unbind();
             Ok(a)
         })
     }
+
+    /// Capture a whole output into `out`, a preallocated uint8 array of shape
+    /// (height, width, channels)
+    ///
+    /// Raise ValueError, before capturing, if the shape doesn't match the output.
+    #[pyo3(signature = (out, screen_name=None, order="BGRA"))]
+    pub fn screenshot_into(
+        &mut self,
+        out: &Bound<'_, PyAny>,
+        screen_name: Option<&str>,
+        order: &str,
+    ) -> anyhow::Result<()> {
+        let order: ChannelOrder = order.parse()?;
+        let out = out.downcast::<PyArray3<u8>>().map_err(|_| {
+            PyTypeError::new_err(format!(
+                "expected a 3-dimensional numpy array of dtype uint8, got {}",
+                out.repr()
+                    .map_or_else(|_| "?".into(), |repr| repr.to_string())
+            ))
+        })?;
+        let shape = self
+            .internal
+            .screenshot_shape(screen_name, order)
+            .map_err(py_error)?;
+        if out.shape() != [shape.0, shape.1, shape.2] {
+            return Err(PyValueError::new_err(format!(
+                "expected an array of shape {shape:?} for {order:?}, got {:?}",
+                out.shape()
+            ))
+            .into());
+        }
+        let mut out = out
+            .try_readwrite()
+            .map_err(|err| PyValueError::new_err(format!("can't write into the array: {err}")))?;
+        self.internal
+            .screenshot_into(&mut out.as_array_mut(), screen_name, order)
+            .map_err(py_error)?;
+        Ok(())
+    }
+
+    /// Wait until the output changes, then capture it; see Frame.damage for what changed
+    #[pyo3(signature = (screen_name=None, region=None, include_cursor=false))]
+    pub fn capture_damage(
+        &mut self,
+        py: Python<'_>,
+        screen_name: Option<&str>,
+        region: Option<(i32, i32, i32, i32)>,
+        include_cursor: bool,
+    ) -> anyhow::Result<Frame> {
+        let region = region.map(Region::from);
+        // waiting for a change can take up to the whole timeout
+        let internal = py
+            .allow_threads(|| {
+                self.internal
+                    .capture_damage(screen_name, region, include_cursor)
+            })
+            .map_err(py_error)?;
+        Ok(Frame { internal })
+    }
+
+    /// Wait until the output, or a region of it, changes, and return the new Frame
+    ///
+    /// Return None if nothing changed within timeout_ms, which defaults to the timeout
+    /// of the connection.
+    #[pyo3(signature = (screen_name=None, region=None, timeout_ms=None))]
+    pub fn wait_for_change(
+        &mut self,
+        py: Python<'_>,
+        screen_name: Option<&str>,
+        region: Option<(i32, i32, i32, i32)>,
+        timeout_ms: Option<u64>,
+    ) -> anyhow::Result<Option<Frame>> {
+        let region = region.map(Region::from);
+        let timeout = timeout_ms.map_or(self.internal.timeout(), Duration::from_millis);
+        let frame = py
+            .allow_threads(|| self.internal.wait_for_change(screen_name, region, timeout))
+            .map_err(py_error)?;
+        Ok(frame.map(|internal| Frame { internal }))
+    }
+
+    /// Wait until the output, or a region of it, didn't change for quiet_ms, and return
+    /// the stable Frame
+    ///
+    /// Return None if it kept changing for timeout_ms, which defaults to the timeout of
+    /// the connection.
+    #[pyo3(signature = (screen_name=None, quiet_ms=200, region=None, timeout_ms=None))]
+    pub fn wait_until_stable(
+        &mut self,
+        py: Python<'_>,
+        screen_name: Option<&str>,
+        quiet_ms: u64,
+        region: Option<(i32, i32, i32, i32)>,
+        timeout_ms: Option<u64>,
+    ) -> anyhow::Result<Option<Frame>> {
+        let region = region.map(Region::from);
+        let timeout = timeout_ms.map_or(self.internal.timeout(), Duration::from_millis);
+        let quiet = Duration::from_millis(quiet_ms);
+        let frame = py
+            .allow_threads(|| {
+                self.internal
+                    .wait_until_stable(screen_name, region, quiet, timeout)
+            })
+            .map_err(py_error)?;
+        Ok(frame.map(|internal| Frame { internal }))
+    }
+
+    /// Capture without copying: the returned Frame exposes the shm buffer to numpy
+    #[pyo3(signature = (screen_name=None, region=None, include_cursor=false))]
+    pub fn capture(
+        &mut self,
+        screen_name: Option<&str>,
+        region: Option<(i32, i32, i32, i32)>,
+        include_cursor: bool,
+    ) -> anyhow::Result<Frame> {
+        let region = region.map(Region::from);
+        let internal = self
+            .internal
+            .capture(screen_name, region, include_cursor)
+            .map_err(py_error)?;
+        Ok(Frame { internal })
+    }
 }
 
 /// A Python module implemented in Rust.
@@ -105,5 +580,10 @@ fn wdotool(m: &Bound<'_, PyModule>) -> PyResult<()> {
     pyo3_log::init();
 
     m.add_class::<Wdotool>()?;
+    m.add_class::<Frame>()?;
+    m.add(
+        "ScreencopyFailed",
+        m.py().get_type_bound::<ScreencopyFailed>(),
+    )?;
     Ok(())
 }
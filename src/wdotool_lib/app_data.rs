@@ -1,21 +1,24 @@
-use std::{collections::HashMap, os::fd::OwnedFd};
+use std::{collections::HashMap, os::fd::OwnedFd, sync::Arc, time::Duration};
 
 use log::info;
 use wayland_client::{
     protocol::{
-        wl_buffer,
+        wl_buffer, wl_callback, wl_display,
         wl_keyboard::{self, KeymapFormat},
         wl_output, wl_registry, wl_seat,
         wl_shm::{self, Format},
         wl_shm_pool,
     },
-    Connection, Dispatch, QueueHandle, WEnum,
+    Connection, Dispatch, Proxy, QueueHandle, WEnum,
 };
 
 use super::{
     screencopy::{zwlr_screencopy_frame_v1, zwlr_screencopy_manager_v1},
+    shm::Mmap,
     virtual_keyboard::{zwp_virtual_keyboard_manager_v1, zwp_virtual_keyboard_v1},
     virtual_pointer::{zwlr_virtual_pointer_manager_v1, zwlr_virtual_pointer_v1},
+    xdg_output::{zxdg_output_manager_v1, zxdg_output_v1},
+    Region,
 };
 
 pub struct Keymap {
@@ -24,6 +27,17 @@ pub struct Keymap {
     pub size: u32,
 }
 
+/// shm formats that can be read back, in order of preference
+pub const SUPPORTED_FORMATS: [Format; 6] = [
+    Format::Xrgb8888,
+    Format::Argb8888,
+    Format::Xbgr8888,
+    Format::Abgr8888,
+    Format::Bgr888,
+    Format::Rgb888,
+];
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Buffer {
     pub format: WEnum<Format>,
     pub width: u32,
@@ -33,44 +47,250 @@ pub struct Buffer {
 
 impl Buffer {
     pub fn size(&self) -> usize {
-        // 4 because R, G, B, A
-        4 * self.height as usize * self.width as usize
+        // rows may be padded, so the stride is used instead of the width
+        self.stride as usize * self.height as usize
     }
 }
 
+/// Buffers kept for each output, so that captures of a few different regions don't
+/// reallocate every time
+const MAX_SHM_BUFFERS_PER_OUTPUT: usize = 4;
+
+/// A wl_buffer backed by its own shm file, reused between captures
+pub struct ShmBuffer {
+    /// registry name of the output the buffer is used for
+    pub output: u32,
+    pub params: Buffer,
+    pub mmap: Arc<Mmap>,
+    pub pool: wl_shm_pool::WlShmPool,
+    pub buffer: wl_buffer::WlBuffer,
+}
+
+impl ShmBuffer {
+    /// True while the pixels are still borrowed, in which case the buffer can't be
+    /// written to
+    pub fn in_use(&self) -> bool {
+        Arc::strong_count(&self.mmap) > 1
+    }
+
+    pub fn destroy(self) {
+        self.buffer.destroy();
+        self.pool.destroy();
+    }
+}
+
+pub struct Mode {
+    pub width: i32,
+    pub height: i32,
+    /// refresh rate in mHz
+    pub refresh: i32,
+}
+
 pub struct Output {
     pub output: wl_output::WlOutput,
     pub name: Option<String>,
+    pub description: Option<String>,
+    pub make: String,
+    pub model: String,
+    /// physical size in millimeters
+    pub physical_width: i32,
+    pub physical_height: i32,
+    /// position in the global compositor space
+    pub x: i32,
+    pub y: i32,
+    pub transform: wl_output::Transform,
+    pub scale: i32,
+    /// current mode
+    pub mode: Option<Mode>,
+    /// virtual pointer bound to this output, created on first use
+    pub pointer: Option<zwlr_virtual_pointer_v1::ZwlrVirtualPointerV1>,
+    pub xdg_output: Option<zxdg_output_v1::ZxdgOutputV1>,
+    /// position and size in the global compositor space, as sent by xdg-output
+    pub xdg_position: Option<(i32, i32)>,
+    pub xdg_size: Option<(i32, i32)>,
+}
+
+impl Output {
+    pub fn new(output: wl_output::WlOutput) -> Self {
+        Output {
+            output,
+            name: None,
+            description: None,
+            make: String::new(),
+            model: String::new(),
+            physical_width: 0,
+            physical_height: 0,
+            x: 0,
+            y: 0,
+            transform: wl_output::Transform::Normal,
+            scale: 1,
+            mode: None,
+            pointer: None,
+            xdg_output: None,
+            xdg_position: None,
+            xdg_size: None,
+        }
+    }
+
+    /// Size of the current mode in pixels, taking the transform into account
+    pub fn pixel_size(&self) -> Option<(i32, i32)> {
+        let mode = self.mode.as_ref()?;
+        match self.transform {
+            wl_output::Transform::_90
+            | wl_output::Transform::_270
+            | wl_output::Transform::Flipped90
+            | wl_output::Transform::Flipped270 => Some((mode.height, mode.width)),
+            _ => Some((mode.width, mode.height)),
+        }
+    }
+
+    /// Position of the output in the global compositor space
+    ///
+    /// Comes from xdg-output when available, as the position of wl_output.geometry is
+    /// not reliable with scaled outputs.
+    pub fn logical_position(&self) -> (i32, i32) {
+        self.xdg_position.unwrap_or((self.x, self.y))
+    }
+
+    /// Size of the output in the global compositor space
+    ///
+    /// Comes from xdg-output when available, otherwise it is computed from the current
+    /// mode, the scale and the transform, which is wrong with fractional scaling.
+    pub fn logical_size(&self) -> Option<(i32, i32)> {
+        if let Some(size) = self.xdg_size {
+            return Some(size);
+        }
+        let (width, height) = self.pixel_size()?;
+        Some((width / self.scale, height / self.scale))
+    }
 }
 
 pub struct Screencopy {
     pub frame: zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1,
-    pub buffer: Option<Buffer>,
+    /// shm buffer types advertised by the compositor
+    pub buffers: Vec<Buffer>,
+    /// true once all the buffer types are advertised
+    pub buffer_done: bool,
+    pub y_invert: bool,
+    /// set when the compositor couldn't copy the frame
+    pub failed: bool,
+    /// presentation time of the copied content, sent with the ready event
+    pub timestamp: Option<Duration>,
+    /// regions that changed since the previous copy, with copy_with_damage
+    pub damage: Vec<Region>,
 }
 
 impl Screencopy {
     pub fn new(frame: zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1) -> Self {
         Screencopy {
             frame,
-            buffer: None,
+            buffers: Vec::new(),
+            buffer_done: false,
+            y_invert: false,
+            failed: false,
+            timestamp: None,
+            damage: Vec::new(),
         }
     }
+
+    /// The advertised buffer type with the preferred supported format
+    pub fn preferred_buffer(&self) -> Option<&Buffer> {
+        SUPPORTED_FORMATS.iter().find_map(|format| {
+            self.buffers
+                .iter()
+                .find(|buffer| buffer.format == WEnum::Value(*format))
+        })
+    }
 }
 
 #[derive(Default)]
 pub struct AppData {
+    pub display: Option<wl_display::WlDisplay>,
+    /// number of wl_display.sync requests sent
+    pub sync_sent: u64,
+    /// last wl_display.sync request answered, so that a late answer isn't mistaken for
+    /// the one of a newer request
+    pub sync_done: u64,
     pub seat: Option<wl_seat::WlSeat>,
     pub vkm: Option<zwp_virtual_keyboard_manager_v1::ZwpVirtualKeyboardManagerV1>,
     pub vpm: Option<zwlr_virtual_pointer_manager_v1::ZwlrVirtualPointerManagerV1>,
     pub keymap: Option<Keymap>,
     pub screencopy_manager: Option<zwlr_screencopy_manager_v1::ZwlrScreencopyManagerV1>,
     pub outputs: HashMap<u32, Output>,
+    /// incremented each time an output is added, removed or changed
+    pub outputs_generation: u64,
+    pub xdg_output_manager: Option<zxdg_output_manager_v1::ZxdgOutputManagerV1>,
     pub shm: Option<wl_shm::WlShm>,
     pub screencopy: Option<Screencopy>,
     pub screencopy_in_progress: bool,
+    /// shm buffers of the previous captures, least recently used first
+    pub shm_buffers: Vec<ShmBuffer>,
 }
 
 impl AppData {
+    fn remove_output(&mut self, name: u32) {
+        let Some(output) = self.outputs.remove(&name) else {
+            return;
+        };
+        if let Some(pointer) = output.pointer {
+            pointer.destroy();
+        }
+        if let Some(xdg_output) = output.xdg_output {
+            xdg_output.destroy();
+        }
+        if output.output.version() >= 3 {
+            output.output.release();
+        }
+        self.release_shm_buffers(name);
+        self.outputs_generation += 1;
+    }
+
+    /// Take an unused shm buffer matching `params` out of the pool
+    pub fn take_shm_buffer(&mut self, output: u32, params: &Buffer) -> Option<ShmBuffer> {
+        let index = self.shm_buffers.iter().position(|buffer| {
+            buffer.output == output && buffer.params == *params && !buffer.in_use()
+        })?;
+        Some(self.shm_buffers.remove(index))
+    }
+
+    /// Give a shm buffer back to the pool, destroying the least recently used buffer of
+    /// the output if there are too many
+    pub fn put_shm_buffer(&mut self, buffer: ShmBuffer) {
+        let output = buffer.output;
+        self.shm_buffers.push(buffer);
+        let count = self
+            .shm_buffers
+            .iter()
+            .filter(|buffer| buffer.output == output)
+            .count();
+        if count > MAX_SHM_BUFFERS_PER_OUTPUT {
+            if let Some(index) = self
+                .shm_buffers
+                .iter()
+                .position(|buffer| buffer.output == output)
+            {
+                self.shm_buffers.remove(index).destroy();
+            }
+        }
+    }
+
+    /// Destroy the shm buffers of an output, e.g. after its mode changed
+    pub fn release_shm_buffers(&mut self, output: u32) {
+        let (released, kept) = std::mem::take(&mut self.shm_buffers)
+            .into_iter()
+            .partition(|buffer| buffer.output == output);
+        self.shm_buffers = kept;
+        for buffer in released {
+            buffer.destroy();
+        }
+    }
+
+    pub fn release_all_shm_buffers(&mut self) {
+        for buffer in self.shm_buffers.drain(..) {
+            buffer.destroy();
+        }
+    }
+
     pub fn get_output_by_name(&self, name: &str) -> Option<&wl_output::WlOutput> {
         for output in self.outputs.values() {
             if let Some(output_name) = &output.name {
@@ -82,6 +302,31 @@ impl AppData {
         None
     }
 
+    pub fn get_output_mut_by_name(&mut self, name: &str) -> Option<&mut Output> {
+        self.outputs
+            .values_mut()
+            .find(|output| output.name.as_deref() == Some(name))
+    }
+
+    /// Size of the bounding box of all outputs in the global compositor space
+    ///
+    /// Outputs may have negative positions, so the box doesn't always start at 0, 0.
+    pub fn layout_size(&self) -> Option<(u32, u32)> {
+        let mut bounds: Option<(i32, i32, i32, i32)> = None;
+        for output in self.outputs.values() {
+            let (width, height) = output.logical_size()?;
+            let (x, y) = output.logical_position();
+            let (min_x, min_y, max_x, max_y) = bounds.unwrap_or((x, y, x + width, y + height));
+            bounds = Some((
+                min_x.min(x),
+                min_y.min(y),
+                max_x.max(x + width),
+                max_y.max(y + height),
+            ));
+        }
+        bounds.map(|(min_x, min_y, max_x, max_y)| ((max_x - min_x) as u32, (max_y - min_y) as u32))
+    }
+
     pub fn all_output_name_set(&self) -> bool {
         for output in self.outputs.values() {
             if output.name.is_none() {
@@ -92,7 +337,9 @@ impl AppData {
     }
 
     pub fn screencopy_buffer_set(&self) -> bool {
-        self.screencopy.as_ref().unwrap().buffer.is_some()
+        self.screencopy
+            .as_ref()
+            .is_some_and(|screencopy| screencopy.buffer_done || screencopy.failed)
     }
 }
 
@@ -162,8 +409,96 @@ impl Dispatch<wl_output::WlOutput, u32> for AppData {
         _: &QueueHandle<AppData>,
     ) {
         info!("WlOutput event for {name}: {:?}", event);
-        if let wl_output::Event::Name { name: output_name } = event {
-            state.outputs.get_mut(name).unwrap().name = Some(output_name);
+        // the output may have been removed while events were still in flight
+        let Some(output) = state.outputs.get_mut(name) else {
+            return;
+        };
+        match event {
+            wl_output::Event::Name { name: output_name } => output.name = Some(output_name),
+            wl_output::Event::Description { description } => output.description = Some(description),
+            wl_output::Event::Geometry {
+                x,
+                y,
+                physical_width,
+                physical_height,
+                make,
+                model,
+                transform,
+                ..
+            } => {
+                output.x = x;
+                output.y = y;
+                output.physical_width = physical_width;
+                output.physical_height = physical_height;
+                output.make = make;
+                output.model = model;
+                if let WEnum::Value(transform) = transform {
+                    output.transform = transform;
+                }
+            }
+            wl_output::Event::Mode {
+                flags: WEnum::Value(flags),
+                width,
+                height,
+                refresh,
+            } if flags.contains(wl_output::Mode::Current) => {
+                output.mode = Some(Mode {
+                    width,
+                    height,
+                    refresh,
+                });
+            }
+            wl_output::Event::Scale { factor } => output.scale = factor.max(1),
+            // sent after every batch of changes, including the initial one
+            wl_output::Event::Done => {
+                // the size of the captures may have changed
+                state.release_shm_buffers(*name);
+                state.outputs_generation += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<zxdg_output_manager_v1::ZxdgOutputManagerV1, ()> for AppData {
+    fn event(
+        _: &mut Self,
+        _: &zxdg_output_manager_v1::ZxdgOutputManagerV1,
+        event: zxdg_output_manager_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<AppData>,
+    ) {
+        info!("Xdg output manager event: {:?}", event);
+    }
+}
+
+impl Dispatch<zxdg_output_v1::ZxdgOutputV1, u32> for AppData {
+    fn event(
+        state: &mut Self,
+        _: &zxdg_output_v1::ZxdgOutputV1,
+        event: zxdg_output_v1::Event,
+        name: &u32,
+        _: &Connection,
+        _: &QueueHandle<AppData>,
+    ) {
+        info!("Xdg output event for {name}: {:?}", event);
+        let Some(output) = state.outputs.get_mut(name) else {
+            return;
+        };
+        match event {
+            zxdg_output_v1::Event::LogicalPosition { x, y } => output.xdg_position = Some((x, y)),
+            zxdg_output_v1::Event::LogicalSize { width, height } => {
+                output.xdg_size = Some((width, height))
+            }
+            // wl_output.name is only available from version 4
+            zxdg_output_v1::Event::Name { name } if output.name.is_none() => {
+                output.name = Some(name)
+            }
+            zxdg_output_v1::Event::Description { description } if output.description.is_none() => {
+                output.description = Some(description)
+            }
+            _ => {}
         }
     }
 }
@@ -171,34 +506,82 @@ impl Dispatch<wl_output::WlOutput, u32> for AppData {
 impl Dispatch<zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1, ()> for AppData {
     fn event(
         state: &mut Self,
-        _: &zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1,
+        frame: &zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1,
         event: zwlr_screencopy_frame_v1::Event,
         _: &(),
         _: &Connection,
         _: &QueueHandle<AppData>,
     ) {
         info!("Screencopy event: {:?}", event);
-        if let zwlr_screencopy_frame_v1::Event::Buffer {
-            format,
-            width,
-            height,
-            stride,
-        } = event
-        {
-            state.screencopy.as_mut().unwrap().buffer = Some(Buffer {
+        let Some(screencopy) = state.screencopy.as_mut() else {
+            return;
+        };
+        match event {
+            zwlr_screencopy_frame_v1::Event::Buffer {
                 format,
                 width,
                 height,
                 stride,
-            });
-        } else if let zwlr_screencopy_frame_v1::Event::Ready {
-            tv_sec_hi: _,
-            tv_sec_lo: _,
-            tv_nsec: _,
-        } = event
-        {
-            // screencopy is ready
-            state.screencopy_in_progress = false;
+            } => {
+                screencopy.buffers.push(Buffer {
+                    format,
+                    width,
+                    height,
+                    stride,
+                });
+                // before version 3, there is a single buffer event and no buffer_done
+                if frame.version() < 3 {
+                    screencopy.buffer_done = true;
+                }
+            }
+            zwlr_screencopy_frame_v1::Event::BufferDone => screencopy.buffer_done = true,
+            zwlr_screencopy_frame_v1::Event::Damage {
+                x,
+                y,
+                width,
+                height,
+            } => screencopy.damage.push(Region {
+                x: x as i32,
+                y: y as i32,
+                width: width as i32,
+                height: height as i32,
+            }),
+            zwlr_screencopy_frame_v1::Event::Flags {
+                flags: WEnum::Value(flags),
+            } => {
+                screencopy.y_invert = flags.contains(zwlr_screencopy_frame_v1::Flags::YInvert);
+            }
+            zwlr_screencopy_frame_v1::Event::Ready {
+                tv_sec_hi,
+                tv_sec_lo,
+                tv_nsec,
+            } => {
+                // screencopy is ready
+                let secs = ((tv_sec_hi as u64) << 32) | tv_sec_lo as u64;
+                screencopy.timestamp = Some(Duration::new(secs, tv_nsec));
+                state.screencopy_in_progress = false;
+            }
+            zwlr_screencopy_frame_v1::Event::Failed => {
+                screencopy.failed = true;
+                state.screencopy_in_progress = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<wl_callback::WlCallback, u64> for AppData {
+    fn event(
+        state: &mut Self,
+        _: &wl_callback::WlCallback,
+        event: wl_callback::Event,
+        sync: &u64,
+        _: &Connection,
+        _: &QueueHandle<AppData>,
+    ) {
+        info!("Callback event: {:?}", event);
+        if let wl_callback::Event::Done { .. } = event {
+            state.sync_done = state.sync_done.max(*sync);
         }
     }
 }
@@ -294,7 +677,10 @@ impl Dispatch<wl_registry::WlRegistry, ()> for AppData {
         qh: &QueueHandle<AppData>,
     ) {
         info!("Registry event: {:?}", event);
-        if let wl_registry::Event::Global {
+        if let wl_registry::Event::GlobalRemove { name } = event {
+            // outputs are the only globals expected to come and go during a session
+            state.remove_output(name);
+        } else if let wl_registry::Event::Global {
             name,
             interface,
             version,
@@ -308,14 +694,19 @@ impl Dispatch<wl_registry::WlRegistry, ()> for AppData {
                 state.vpm = Some(registry.bind(name, version, qh, ()));
             } else if interface == *"zwlr_screencopy_manager_v1" {
                 state.screencopy_manager = Some(registry.bind(name, version, qh, ()));
+            } else if interface == *"zxdg_output_manager_v1" {
+                let manager: zxdg_output_manager_v1::ZxdgOutputManagerV1 =
+                    registry.bind(name, version, qh, ());
+                for (name, output) in state.outputs.iter_mut() {
+                    output.xdg_output = Some(manager.get_xdg_output(&output.output, qh, *name));
+                }
+                state.xdg_output_manager = Some(manager);
             } else if interface == *"wl_output" {
-                state.outputs.insert(
-                    name,
-                    Output {
-                        output: registry.bind(name, version, qh, name),
-                        name: None,
-                    },
-                );
+                let mut output = Output::new(registry.bind(name, version, qh, name));
+                if let Some(manager) = &state.xdg_output_manager {
+                    output.xdg_output = Some(manager.get_xdg_output(&output.output, qh, name));
+                }
+                state.outputs.insert(name, output);
             } else if interface == *"wl_shm" {
                 state.shm = Some(registry.bind(name, version, qh, ()));
             }
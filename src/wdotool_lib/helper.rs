@@ -1,23 +1,28 @@
 use std::{
-    env,
-    io::Read,
+    env, io,
     os::{
-        fd::{AsFd, BorrowedFd, IntoRawFd},
+        fd::{AsFd, AsRawFd, BorrowedFd, IntoRawFd},
         unix::net::UnixStream,
     },
     path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use anyhow::{Context, Result};
-use ndarray::{Array, Array3};
+use log::warn;
 use wayland_client::{
-    protocol::{wl_keyboard, wl_shm},
-    Connection, EventQueue, QueueHandle,
+    backend::WaylandError,
+    protocol::{wl_keyboard, wl_output::WlOutput},
+    Connection, EventQueue, Proxy, QueueHandle, WEnum,
 };
 
-use crate::wdotool_lib::app_data::Screencopy;
-
-use super::{app_data::AppData, shm::create_shm_file, virtual_keyboard::zwp_virtual_keyboard_v1};
+use super::{
+    app_data::{AppData, Buffer, Screencopy, ShmBuffer},
+    shm::{create_shm_file, Mmap},
+    virtual_keyboard::zwp_virtual_keyboard_v1,
+    xkb, Frame, Region, RetryableError,
+};
 
 /// Connect to the wayland compositor
 ///
@@ -51,14 +56,107 @@ pub fn connect_wayland(display: Option<&str>) -> Result<Connection> {
     Connection::from_socket(socket).context("failed to connect to wayland compositor")
 }
 
+/// Dispatch the events of the compositor until `done` returns true
+///
+/// Fails with [`RetryableError::Timeout`] if it takes longer than `timeout`.
+pub fn dispatch_until(
+    app_data: &mut AppData,
+    event_queue: &mut EventQueue<AppData>,
+    timeout: Duration,
+    done: impl Fn(&AppData) -> bool,
+) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        event_queue.dispatch_pending(app_data)?;
+        if done(app_data) {
+            return Ok(());
+        }
+        event_queue.flush()?;
+
+        // another thread may be reading the socket, in which case there are already
+        // events to dispatch
+        let Some(guard) = event_queue.prepare_read() else {
+            continue;
+        };
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(RetryableError::Timeout(timeout).into());
+        }
+
+        let mut pollfd = libc::pollfd {
+            fd: guard.connection_fd().as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // round up so that a sub-millisecond remaining time doesn't busy loop
+        let timeout_ms = remaining.as_micros().div_ceil(1000).min(i32::MAX as u128) as i32;
+        match unsafe { libc::poll(&mut pollfd, 1, timeout_ms) } {
+            0 => return Err(RetryableError::Timeout(timeout).into()),
+            ret if ret < 0 => {
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err).context("failed to poll the wayland socket");
+                }
+            }
+            _ => match guard.read() {
+                Ok(_) => {}
+                Err(WaylandError::Io(err)) if err.kind() == io::ErrorKind::WouldBlock => {}
+                Err(err) => return Err(err.into()),
+            },
+        }
+    }
+}
+
+/// Like `EventQueue::roundtrip`, but fails if the compositor doesn't answer within `timeout`
+pub fn roundtrip(
+    app_data: &mut AppData,
+    qh: &QueueHandle<AppData>,
+    event_queue: &mut EventQueue<AppData>,
+    timeout: Duration,
+) -> Result<()> {
+    let display = app_data.display.clone().context("no wayland display")?;
+    app_data.sync_sent += 1;
+    let sync = app_data.sync_sent;
+    display.sync(qh, sync);
+    dispatch_until(app_data, event_queue, timeout, |app_data| {
+        app_data.sync_done >= sync
+    })
+}
+
+/// Create a virtual keyboard with the keymap of the current keyboard
+///
+/// Without a xkb keymap from the compositor, there is no virtual keyboard, and if the
+/// keymap can't be parsed, keys can only be sent by keycode. The mouse and screenshots
+/// work in both cases.
 pub fn setup_virtual_keyboard(
     mut app_data: AppData,
     qh: &QueueHandle<AppData>,
     event_queue: &mut EventQueue<AppData>,
-) -> (AppData, zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1) {
+    timeout: Duration,
+) -> Result<(
+    AppData,
+    Option<zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1>,
+    Option<xkb::Keymap>,
+)> {
     // get keymap from current keyboard
     app_data.seat.as_ref().unwrap().get_keyboard(qh, ());
-    event_queue.roundtrip(&mut app_data).unwrap();
+    roundtrip(&mut app_data, qh, event_queue, timeout)?;
+
+    let keymap = app_data
+        .keymap
+        .take()
+        .filter(|keymap| keymap.format == WEnum::Value(wl_keyboard::KeymapFormat::XkbV1));
+    let Some(keymap) = keymap else {
+        warn!("no xkb keymap received, keys can't be sent");
+        return Ok((app_data, None, None));
+    };
+    let parsed_keymap = match xkb::Keymap::from_fd(&keymap.fd, keymap.size) {
+        Ok(parsed_keymap) => Some(parsed_keymap),
+        Err(err) => {
+            warn!("failed to parse the keymap, keys can only be sent by keycode: {err:#}");
+            None
+        }
+    };
 
     let virtual_keyboard = app_data.vkm.as_ref().unwrap().create_virtual_keyboard(
         app_data.seat.as_ref().unwrap(),
@@ -66,27 +164,159 @@ pub fn setup_virtual_keyboard(
         (),
     );
     // upload_keymap we got from the current keyboard
-    let keymap = app_data.keymap.unwrap();
-    app_data.keymap = None;
-
     let fd = keymap.fd;
     let fd = fd.into_raw_fd();
     let fd = unsafe { BorrowedFd::borrow_raw(fd) };
     virtual_keyboard.keymap(wl_keyboard::KeymapFormat::XkbV1.into(), fd, keymap.size);
-    event_queue.roundtrip(&mut app_data).unwrap();
+    roundtrip(&mut app_data, qh, event_queue, timeout)?;
 
-    (app_data, virtual_keyboard)
+    Ok((app_data, Some(virtual_keyboard), parsed_keymap))
 }
 
-pub fn screenshot(
+/// Capture an output, or a region of it
+///
+/// With `with_damage`, the copy only happens once the content changed, and the frame
+/// holds the damaged rectangles.
+#[allow(clippy::too_many_arguments)]
+pub fn capture(
     app_data: &mut AppData,
     qh: &QueueHandle<AppData>,
     event_queue: &mut EventQueue<AppData>,
     output_name: Option<&str>,
-) -> Result<Array3<u8>> {
-    let output = match output_name {
+    region: Option<Region>,
+    include_cursor: bool,
+    with_damage: bool,
+    timeout: Duration,
+) -> Result<Frame> {
+    let (output_id, output) = capture_output(app_data, output_name)?;
+
+    let screencopy_manager = app_data
+        .screencopy_manager
+        .as_ref()
+        .context("no screencopy manager")?;
+    let overlay_cursor = include_cursor as i32;
+    let screencopy_frame = match region {
+        Some(region) => screencopy_manager.capture_output_region(
+            overlay_cursor,
+            &output,
+            region.x,
+            region.y,
+            region.width,
+            region.height,
+            qh,
+            (),
+        ),
+        None => screencopy_manager.capture_output(overlay_cursor, &output, qh, ()),
+    };
+    app_data.screencopy = Some(Screencopy::new(screencopy_frame));
+
+    // wait until all the buffer types are advertised
+    let result = dispatch_until(
+        app_data,
+        event_queue,
+        timeout,
+        AppData::screencopy_buffer_set,
+    );
+    check_screencopy(app_data, result)?;
+
+    let screencopy = app_data.screencopy.as_ref().unwrap();
+    let Some(buffer_param) = screencopy.preferred_buffer().cloned() else {
+        end_screencopy(app_data);
+        anyhow::bail!("the compositor doesn't offer any supported shm buffer format");
+    };
+    let WEnum::Value(format) = buffer_param.format else {
+        unreachable!("preferred_buffer only returns known formats")
+    };
+
+    let shm_buffer = match app_data.take_shm_buffer(output_id, &buffer_param) {
+        Some(shm_buffer) => shm_buffer,
+        None => match create_shm_buffer(app_data, qh, output_id, &buffer_param) {
+            Ok(shm_buffer) => shm_buffer,
+            Err(err) => {
+                end_screencopy(app_data);
+                return Err(err);
+            }
+        },
+    };
+
+    let frame = &app_data.screencopy.as_ref().unwrap().frame;
+    if !with_damage {
+        frame.copy(&shm_buffer.buffer);
+    } else if frame.version() >= 2 {
+        frame.copy_with_damage(&shm_buffer.buffer);
+    } else {
+        app_data.put_shm_buffer(shm_buffer);
+        end_screencopy(app_data);
+        anyhow::bail!("the compositor doesn't support damage tracking (screencopy version 2)");
+    }
+    app_data.screencopy_in_progress = true;
+
+    let result = dispatch_until(app_data, event_queue, timeout, |app_data| {
+        !app_data.screencopy_in_progress
+    });
+    let mmap = shm_buffer.mmap.clone();
+    if result.is_err() {
+        // the frame still refers to the buffer, so it is destroyed first, and the
+        // compositor may still write into the buffer, so it can't be reused
+        end_screencopy(app_data);
+        shm_buffer.destroy();
+    } else if app_data.outputs.contains_key(&output_id) {
+        app_data.put_shm_buffer(shm_buffer);
+    } else {
+        // the output was removed during the capture, and its buffers with it
+        shm_buffer.destroy();
+    }
+    check_screencopy(app_data, result)?;
+
+    let screencopy = app_data.screencopy.as_ref().unwrap();
+    let y_invert = screencopy.y_invert;
+    let timestamp = screencopy.timestamp.unwrap_or_default();
+    let damage = with_damage.then(|| {
+        screencopy
+            .damage
+            .iter()
+            .map(|damage| {
+                // damage is in buffer coordinates, so it is flipped like the pixels
+                let y = if y_invert {
+                    buffer_param.height as i32 - damage.y - damage.height
+                } else {
+                    damage.y
+                };
+                Region { y, ..*damage }
+            })
+            .collect()
+    });
+    end_screencopy(app_data);
+
+    let output = app_data
+        .outputs
+        .get(&output_id)
+        .context("the output was removed during the capture")?;
+
+    Ok(Frame {
+        mmap,
+        format,
+        width: buffer_param.width,
+        height: buffer_param.height,
+        stride: buffer_param.stride,
+        y_invert,
+        timestamp,
+        damage,
+        output_name: output.name.clone(),
+        scale: output.scale,
+        transform: output.transform,
+    })
+}
+
+/// Registry name and proxy of the output to capture
+///
+/// Without a name, there must be a single output.
+pub fn capture_output(app_data: &AppData, output_name: Option<&str>) -> Result<(u32, WlOutput)> {
+    let (id, output) = match output_name {
         Some(name) => app_data
-            .get_output_by_name(name)
+            .outputs
+            .iter()
+            .find(|(_, output)| output.name.as_deref() == Some(name))
             .context(format!("no WLOutput with name {name}"))?,
         None => {
             if app_data.outputs.len() > 1 {
@@ -95,68 +325,74 @@ pub fn screenshot(
                 )
             }
 
-            let k_v = app_data
+            app_data
                 .outputs
                 .iter()
                 .next()
-                .context("at least one display need to be set")?;
-            &k_v.1.output
+                .context("at least one display need to be set")?
         }
     };
+    Ok((*id, output.output.clone()))
+}
 
-    let screencopy_frame = app_data
-        .screencopy_manager
-        .as_ref()
-        .context("no screencopy manager")?
-        .capture_output(0, output, qh, ());
-    app_data.screencopy = Some(Screencopy::new(screencopy_frame));
-    event_queue.roundtrip(app_data)?;
-
-    while !app_data.screencopy_buffer_set() {
-        event_queue.blocking_dispatch(app_data)?;
-    }
-
-    let buffer_param = app_data
-        .screencopy
-        .as_ref()
-        .unwrap()
-        .buffer
-        .as_ref()
-        .unwrap();
-
-    let width = buffer_param.width as i32;
-    let height = buffer_param.height as i32;
-    let stride = buffer_param.stride as i32;
-
-    let mut file = create_shm_file(buffer_param.size())?;
-    let fd = file.as_fd();
+/// Allocate a shm file and the wl_buffer wrapping it
+fn create_shm_buffer(
+    app_data: &AppData,
+    qh: &QueueHandle<AppData>,
+    output: u32,
+    params: &Buffer,
+) -> Result<ShmBuffer> {
+    let WEnum::Value(format) = params.format else {
+        anyhow::bail!("unknown shm format {:?}", params.format);
+    };
+    let file = create_shm_file(params.size())?;
+    let mmap = Mmap::new(&file, params.size())?;
 
-    let wl_shm_pool = app_data
+    let pool = app_data
         .shm
         .as_ref()
         .context("no shared memory")?
-        .create_pool(fd, buffer_param.size() as i32, qh, ());
-
-    event_queue.roundtrip(app_data)?;
-
-    let buffer =
-        wl_shm_pool.create_buffer(0, width, height, stride, wl_shm::Format::Xrgb8888, qh, ());
-    event_queue.roundtrip(app_data)?;
+        .create_pool(file.as_fd(), params.size() as i32, qh, ());
+    let buffer = pool.create_buffer(
+        0,
+        params.width as i32,
+        params.height as i32,
+        params.stride as i32,
+        format,
+        qh,
+        (),
+    );
 
-    app_data.screencopy.as_ref().unwrap().frame.copy(&buffer);
-    app_data.screencopy_in_progress = true;
+    Ok(ShmBuffer {
+        output,
+        params: params.clone(),
+        mmap: Arc::new(mmap),
+        pool,
+        buffer,
+    })
+}
 
-    while app_data.screencopy_in_progress {
-        event_queue.blocking_dispatch(app_data)?;
+/// Destroy the frame of the current screencopy
+fn end_screencopy(app_data: &mut AppData) {
+    if let Some(screencopy) = app_data.screencopy.take() {
+        screencopy.frame.destroy();
     }
+    app_data.screencopy_in_progress = false;
+}
 
-    app_data.screencopy.as_ref().unwrap().frame.destroy();
-    app_data.screencopy = None;
-
-    let mut buf = vec![0u8; height as usize * width as usize * 4];
-    file.read_exact(&mut buf[..])?;
-    let array = Array::from_vec(buf)
-        .to_shape((height as usize, width as usize, 4))?
-        .to_owned();
-    Ok(array)
+/// End the screencopy if waiting for it failed, timed out, or if the compositor reported
+/// a failure
+fn check_screencopy(app_data: &mut AppData, result: Result<()>) -> Result<()> {
+    let failed = app_data
+        .screencopy
+        .as_ref()
+        .is_some_and(|screencopy| screencopy.failed);
+    if result.is_err() || failed {
+        end_screencopy(app_data);
+    }
+    result?;
+    if failed {
+        return Err(RetryableError::ScreencopyFailed.into());
+    }
+    Ok(())
 }
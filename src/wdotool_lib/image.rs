@@ -0,0 +1,213 @@
+use anyhow::Result;
+use ndarray::{s, Array3, ArrayView3, ArrayViewMut3, Zip};
+use wayland_client::protocol::wl_shm::Format;
+
+/// Byte offsets of the red, green and blue channels, and of the alpha channel if any,
+/// in a pixel of a shm format
+///
+/// The shm formats are little-endian, so `Xrgb8888` is stored as B, G, R, X.
+pub fn channel_offsets(format: Format) -> Option<([usize; 3], Option<usize>)> {
+    match format {
+        Format::Xrgb8888 => Some(([2, 1, 0], None)),
+        Format::Argb8888 => Some(([2, 1, 0], Some(3))),
+        Format::Xbgr8888 => Some(([0, 1, 2], None)),
+        Format::Abgr8888 => Some(([0, 1, 2], Some(3))),
+        Format::Bgr888 => Some(([0, 1, 2], None)),
+        Format::Rgb888 => Some(([2, 1, 0], None)),
+        _ => None,
+    }
+}
+
+/// Size of a pixel of a shm format, in bytes
+pub fn bytes_per_pixel(format: Format) -> usize {
+    match format {
+        Format::Bgr888 | Format::Rgb888 => 3,
+        _ => 4,
+    }
+}
+
+/// Channel order of the arrays returned to the user
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChannelOrder {
+    Rgb,
+    Bgr,
+    Rgba,
+    Bgra,
+    /// single luma channel, ITU-R BT.601 weights
+    Gray,
+}
+
+impl ChannelOrder {
+    pub fn channels(&self) -> usize {
+        match self {
+            ChannelOrder::Rgb | ChannelOrder::Bgr => 3,
+            ChannelOrder::Rgba | ChannelOrder::Bgra => 4,
+            ChannelOrder::Gray => 1,
+        }
+    }
+}
+
+impl std::str::FromStr for ChannelOrder {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_uppercase().as_str() {
+            "RGB" => Ok(ChannelOrder::Rgb),
+            "BGR" => Ok(ChannelOrder::Bgr),
+            "RGBA" => Ok(ChannelOrder::Rgba),
+            "BGRA" => Ok(ChannelOrder::Bgra),
+            "GRAY" => Ok(ChannelOrder::Gray),
+            _ => anyhow::bail!("unknown channel order {name:?}"),
+        }
+    }
+}
+
+/// Convert pixels of a shm format to an array of shape (height, width, channels)
+///
+/// The alpha channel is 255 when the format doesn't have one.
+pub fn convert(pixels: ArrayView3<u8>, format: Format, order: ChannelOrder) -> Result<Array3<u8>> {
+    let (height, width, _) = pixels.dim();
+    let mut converted = Array3::zeros((height, width, order.channels()));
+    convert_into(pixels, format, order, converted.view_mut())?;
+    Ok(converted)
+}
+
+/// Like [`convert`], but writes into `out`, which must have the shape
+/// (height, width, channels)
+pub fn convert_into(
+    pixels: ArrayView3<u8>,
+    format: Format,
+    order: ChannelOrder,
+    mut out: ArrayViewMut3<u8>,
+) -> Result<()> {
+    let Some(([r, g, b], a)) = channel_offsets(format) else {
+        anyhow::bail!("unsupported format {format:?}");
+    };
+    let (height, width, _) = pixels.dim();
+    let shape = (height, width, order.channels());
+    if out.dim() != shape {
+        anyhow::bail!(
+            "expected an array of shape {shape:?} for {order:?}, got {:?}",
+            out.dim()
+        );
+    }
+
+    let sources = match order {
+        ChannelOrder::Rgb | ChannelOrder::Rgba => vec![r, g, b],
+        ChannelOrder::Bgr | ChannelOrder::Bgra => vec![b, g, r],
+        ChannelOrder::Gray => {
+            Zip::from(out.slice_mut(s![.., .., 0]))
+                .and(pixels.slice(s![.., .., r]))
+                .and(pixels.slice(s![.., .., g]))
+                .and(pixels.slice(s![.., .., b]))
+                .for_each(|gray, &r, &g, &b| {
+                    *gray = ((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8
+                });
+            return Ok(());
+        }
+    };
+    for (channel, source) in sources.into_iter().enumerate() {
+        out.slice_mut(s![.., .., channel])
+            .assign(&pixels.slice(s![.., .., source]));
+    }
+    if order.channels() == 4 {
+        match a {
+            Some(a) => out
+                .slice_mut(s![.., .., 3])
+                .assign(&pixels.slice(s![.., .., a])),
+            None => out.slice_mut(s![.., .., 3]).fill(255),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // one pixel with red 10, green 20 and blue 30, stored in the byte order of the format
+    fn pixel(format: Format) -> Array3<u8> {
+        let bytes = match format {
+            Format::Xrgb8888 => vec![30, 20, 10, 0],
+            Format::Argb8888 => vec![30, 20, 10, 40],
+            Format::Xbgr8888 => vec![10, 20, 30, 0],
+            Format::Abgr8888 => vec![10, 20, 30, 40],
+            Format::Bgr888 => vec![10, 20, 30],
+            Format::Rgb888 => vec![30, 20, 10],
+            _ => unreachable!(),
+        };
+        let channels = bytes.len();
+        Array3::from_shape_vec((1, 1, channels), bytes).unwrap()
+    }
+
+    fn converted(format: Format, order: ChannelOrder) -> Vec<u8> {
+        convert(pixel(format).view(), format, order)
+            .unwrap()
+            .into_raw_vec()
+    }
+
+    #[test]
+    fn little_endian_formats() {
+        for format in [
+            Format::Xrgb8888,
+            Format::Argb8888,
+            Format::Xbgr8888,
+            Format::Abgr8888,
+            Format::Bgr888,
+            Format::Rgb888,
+        ] {
+            assert_eq!(converted(format, ChannelOrder::Rgb), vec![10, 20, 30]);
+            assert_eq!(converted(format, ChannelOrder::Bgr), vec![30, 20, 10]);
+        }
+    }
+
+    #[test]
+    fn alpha() {
+        assert_eq!(
+            converted(Format::Argb8888, ChannelOrder::Rgba),
+            vec![10, 20, 30, 40]
+        );
+        assert_eq!(
+            converted(Format::Abgr8888, ChannelOrder::Bgra),
+            vec![30, 20, 10, 40]
+        );
+        // formats without alpha are opaque
+        assert_eq!(
+            converted(Format::Xrgb8888, ChannelOrder::Bgra),
+            vec![30, 20, 10, 255]
+        );
+        assert_eq!(
+            converted(Format::Rgb888, ChannelOrder::Rgba),
+            vec![10, 20, 30, 255]
+        );
+    }
+
+    #[test]
+    fn gray() {
+        // (299 * 10 + 587 * 20 + 114 * 30) / 1000
+        assert_eq!(converted(Format::Xrgb8888, ChannelOrder::Gray), vec![18]);
+        assert_eq!(converted(Format::Bgr888, ChannelOrder::Gray), vec![18]);
+    }
+
+    #[test]
+    fn convert_into_checks_the_shape() {
+        let mut out = Array3::zeros((1, 1, 4));
+        let pixels = pixel(Format::Xrgb8888);
+        assert!(convert_into(
+            pixels.view(),
+            Format::Xrgb8888,
+            ChannelOrder::Rgb,
+            out.view_mut()
+        )
+        .is_err());
+        convert_into(
+            pixels.view(),
+            Format::Xrgb8888,
+            ChannelOrder::Rgba,
+            out.view_mut(),
+        )
+        .unwrap();
+        assert_eq!(out.into_raw_vec(), vec![10, 20, 30, 255]);
+        assert!(convert(pixels.view(), Format::Rgb565, ChannelOrder::Rgb).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+// The X11 keysym table (the one xkbcommon-keysyms.h is generated from), to turn the
+// symbol names found in a keymap into keysym values and characters.
+//
+// The tables are generated by scripts/generate_keysyms.py.
+
+include!("keysyms_table.rs");
+
+/// Get the keysym value of a keysym name, as written in a keymap
+///
+/// Single letters and digits map to themselves, `U1E9E` style names are unicode
+/// keysyms and `0x1008ff13` style names are raw keysym values.
+pub fn keysym_from_name(name: &str) -> Option<u32> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii_alphanumeric() {
+            return Some(c as u32);
+        }
+    }
+
+    if let Some(hex) = name.strip_prefix("0x") {
+        return u32::from_str_radix(hex, 16).ok();
+    }
+
+    if let Some(hex) = name.strip_prefix('U') {
+        if hex.len() >= 4 {
+            if let Ok(code_point) = u32::from_str_radix(hex, 16) {
+                return Some(0x0100_0000 + code_point);
+            }
+        }
+    }
+
+    KEYSYMS
+        .binary_search_by(|(keysym_name, _)| keysym_name.cmp(&name))
+        .ok()
+        .map(|index| KEYSYMS[index].1)
+}
+
+/// Get the character produced by a keysym, if any
+pub fn keysym_to_char(keysym: u32) -> Option<char> {
+    match keysym {
+        0x0020..=0x007e | 0x00a0..=0x00ff => char::from_u32(keysym),
+        0x0100_0100..=0x0110_ffff => char::from_u32(keysym - 0x0100_0000),
+        _ => KEYSYM_CHARS
+            .binary_search_by_key(&keysym, |(value, _)| *value)
+            .ok()
+            .map(|index| KEYSYM_CHARS[index].1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted() {
+        assert!(KEYSYMS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(KEYSYM_CHARS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn names_to_chars() {
+        let char_of = |name| keysym_from_name(name).and_then(keysym_to_char);
+        assert_eq!(char_of("a"), Some('a'));
+        assert_eq!(char_of("eacute"), Some('é'));
+        assert_eq!(char_of("Cyrillic_a"), Some('а'));
+        assert_eq!(char_of("Greek_OMEGA"), Some('Ω'));
+        assert_eq!(char_of("hebrew_aleph"), Some('א'));
+        assert_eq!(char_of("EuroSign"), Some('€'));
+        assert_eq!(char_of("U1E9E"), Some('ẞ'));
+        assert_eq!(char_of("Tab"), Some('\t'));
+        assert_eq!(char_of("Shift_L"), None);
+        assert_eq!(keysym_from_name("XF86AudioMute"), Some(0x1008ff12));
+        assert_eq!(keysym_from_name("not_a_keysym"), None);
+    }
+}
@@ -0,0 +1,3327 @@
+// @generated by scripts/generate_keysyms.py from the X11 keysym headers,
+// do not edit
+
+/// Keysym names and values, sorted by name
+const KEYSYMS: &[(&str, u32)] = &[
+    ("0", 0x0030),
+    ("1", 0x0031),
+    ("2", 0x0032),
+    ("3", 0x0033),
+    ("3270_AltCursor", 0xfd10),
+    ("3270_Attn", 0xfd0e),
+    ("3270_BackTab", 0xfd05),
+    ("3270_ChangeScreen", 0xfd19),
+    ("3270_Copy", 0xfd15),
+    ("3270_CursorBlink", 0xfd0f),
+    ("3270_CursorSelect", 0xfd1c),
+    ("3270_DeleteWord", 0xfd1a),
+    ("3270_Duplicate", 0xfd01),
+    ("3270_Enter", 0xfd1e),
+    ("3270_EraseEOF", 0xfd06),
+    ("3270_EraseInput", 0xfd07),
+    ("3270_ExSelect", 0xfd1b),
+    ("3270_FieldMark", 0xfd02),
+    ("3270_Ident", 0xfd13),
+    ("3270_Jump", 0xfd12),
+    ("3270_KeyClick", 0xfd11),
+    ("3270_Left2", 0xfd04),
+    ("3270_PA1", 0xfd0a),
+    ("3270_PA2", 0xfd0b),
+    ("3270_PA3", 0xfd0c),
+    ("3270_Play", 0xfd16),
+    ("3270_PrintScreen", 0xfd1d),
+    ("3270_Quit", 0xfd09),
+    ("3270_Record", 0xfd18),
+    ("3270_Reset", 0xfd08),
+    ("3270_Right2", 0xfd03),
+    ("3270_Rule", 0xfd14),
+    ("3270_Setup", 0xfd17),
+    ("3270_Test", 0xfd0d),
+    ("4", 0x0034),
+    ("5", 0x0035),
+    ("6", 0x0036),
+    ("7", 0x0037),
+    ("8", 0x0038),
+    ("9", 0x0039),
+    ("A", 0x0041),
+    ("AE", 0x00c6),
+    ("Aacute", 0x00c1),
+    ("Abelowdot", 0x1001ea0),
+    ("Abreve", 0x01c3),
+    ("Abreveacute", 0x1001eae),
+    ("Abrevebelowdot", 0x1001eb6),
+    ("Abrevegrave", 0x1001eb0),
+    ("Abrevehook", 0x1001eb2),
+    ("Abrevetilde", 0x1001eb4),
+    ("AccessX_Enable", 0xfe70),
+    ("AccessX_Feedback_Enable", 0xfe71),
+    ("Acircumflex", 0x00c2),
+    ("Acircumflexacute", 0x1001ea4),
+    ("Acircumflexbelowdot", 0x1001eac),
+    ("Acircumflexgrave", 0x1001ea6),
+    ("Acircumflexhook", 0x1001ea8),
+    ("Acircumflextilde", 0x1001eaa),
+    ("Adiaeresis", 0x00c4),
+    ("Agrave", 0x00c0),
+    ("Ahook", 0x1001ea2),
+    ("Alt_L", 0xffe9),
+    ("Alt_R", 0xffea),
+    ("Amacron", 0x03c0),
+    ("Aogonek", 0x01a1),
+    ("Arabic_0", 0x1000660),
+    ("Arabic_1", 0x1000661),
+    ("Arabic_2", 0x1000662),
+    ("Arabic_3", 0x1000663),
+    ("Arabic_4", 0x1000664),
+    ("Arabic_5", 0x1000665),
+    ("Arabic_6", 0x1000666),
+    ("Arabic_7", 0x1000667),
+    ("Arabic_8", 0x1000668),
+    ("Arabic_9", 0x1000669),
+    ("Arabic_ain", 0x05d9),
+    ("Arabic_alef", 0x05c7),
+    ("Arabic_alefmaksura", 0x05e9),
+    ("Arabic_beh", 0x05c8),
+    ("Arabic_comma", 0x05ac),
+    ("Arabic_dad", 0x05d6),
+    ("Arabic_dal", 0x05cf),
+    ("Arabic_damma", 0x05ef),
+    ("Arabic_dammatan", 0x05ec),
+    ("Arabic_ddal", 0x1000688),
+    ("Arabic_farsi_yeh", 0x10006cc),
+    ("Arabic_fatha", 0x05ee),
+    ("Arabic_fathatan", 0x05eb),
+    ("Arabic_feh", 0x05e1),
+    ("Arabic_fullstop", 0x10006d4),
+    ("Arabic_gaf", 0x10006af),
+    ("Arabic_ghain", 0x05da),
+    ("Arabic_ha", 0x05e7),
+    ("Arabic_hah", 0x05cd),
+    ("Arabic_hamza", 0x05c1),
+    ("Arabic_hamza_above", 0x1000654),
+    ("Arabic_hamza_below", 0x1000655),
+    ("Arabic_hamzaonalef", 0x05c3),
+    ("Arabic_hamzaonwaw", 0x05c4),
+    ("Arabic_hamzaonyeh", 0x05c6),
+    ("Arabic_hamzaunderalef", 0x05c5),
+    ("Arabic_heh", 0x05e7),
+    ("Arabic_heh_doachashmee", 0x10006be),
+    ("Arabic_heh_goal", 0x10006c1),
+    ("Arabic_jeem", 0x05cc),
+    ("Arabic_jeh", 0x1000698),
+    ("Arabic_kaf", 0x05e3),
+    ("Arabic_kasra", 0x05f0),
+    ("Arabic_kasratan", 0x05ed),
+    ("Arabic_keheh", 0x10006a9),
+    ("Arabic_khah", 0x05ce),
+    ("Arabic_lam", 0x05e4),
+    ("Arabic_madda_above", 0x1000653),
+    ("Arabic_maddaonalef", 0x05c2),
+    ("Arabic_meem", 0x05e5),
+    ("Arabic_noon", 0x05e6),
+    ("Arabic_noon_ghunna", 0x10006ba),
+    ("Arabic_peh", 0x100067e),
+    ("Arabic_percent", 0x100066a),
+    ("Arabic_qaf", 0x05e2),
+    ("Arabic_question_mark", 0x05bf),
+    ("Arabic_ra", 0x05d1),
+    ("Arabic_rreh", 0x1000691),
+    ("Arabic_sad", 0x05d5),
+    ("Arabic_seen", 0x05d3),
+    ("Arabic_semicolon", 0x05bb),
+    ("Arabic_shadda", 0x05f1),
+    ("Arabic_sheen", 0x05d4),
+    ("Arabic_sukun", 0x05f2),
+    ("Arabic_superscript_alef", 0x1000670),
+    ("Arabic_switch", 0xff7e),
+    ("Arabic_tah", 0x05d7),
+    ("Arabic_tatweel", 0x05e0),
+    ("Arabic_tcheh", 0x1000686),
+    ("Arabic_teh", 0x05ca),
+    ("Arabic_tehmarbuta", 0x05c9),
+    ("Arabic_thal", 0x05d0),
+    ("Arabic_theh", 0x05cb),
+    ("Arabic_tteh", 0x1000679),
+    ("Arabic_veh", 0x10006a4),
+    ("Arabic_waw", 0x05e8),
+    ("Arabic_yeh", 0x05ea),
+    ("Arabic_yeh_baree", 0x10006d2),
+    ("Arabic_zah", 0x05d8),
+    ("Arabic_zain", 0x05d2),
+    ("Aring", 0x00c5),
+    ("Armenian_AT", 0x1000538),
+    ("Armenian_AYB", 0x1000531),
+    ("Armenian_BEN", 0x1000532),
+    ("Armenian_CHA", 0x1000549),
+    ("Armenian_DA", 0x1000534),
+    ("Armenian_DZA", 0x1000541),
+    ("Armenian_E", 0x1000537),
+    ("Armenian_FE", 0x1000556),
+    ("Armenian_GHAT", 0x1000542),
+    ("Armenian_GIM", 0x1000533),
+    ("Armenian_HI", 0x1000545),
+    ("Armenian_HO", 0x1000540),
+    ("Armenian_INI", 0x100053b),
+    ("Armenian_JE", 0x100054b),
+    ("Armenian_KE", 0x1000554),
+    ("Armenian_KEN", 0x100053f),
+    ("Armenian_KHE", 0x100053d),
+    ("Armenian_LYUN", 0x100053c),
+    ("Armenian_MEN", 0x1000544),
+    ("Armenian_NU", 0x1000546),
+    ("Armenian_O", 0x1000555),
+    ("Armenian_PE", 0x100054a),
+    ("Armenian_PYUR", 0x1000553),
+    ("Armenian_RA", 0x100054c),
+    ("Armenian_RE", 0x1000550),
+    ("Armenian_SE", 0x100054d),
+    ("Armenian_SHA", 0x1000547),
+    ("Armenian_TCHE", 0x1000543),
+    ("Armenian_TO", 0x1000539),
+    ("Armenian_TSA", 0x100053e),
+    ("Armenian_TSO", 0x1000551),
+    ("Armenian_TYUN", 0x100054f),
+    ("Armenian_VEV", 0x100054e),
+    ("Armenian_VO", 0x1000548),
+    ("Armenian_VYUN", 0x1000552),
+    ("Armenian_YECH", 0x1000535),
+    ("Armenian_ZA", 0x1000536),
+    ("Armenian_ZHE", 0x100053a),
+    ("Armenian_accent", 0x100055b),
+    ("Armenian_amanak", 0x100055c),
+    ("Armenian_apostrophe", 0x100055a),
+    ("Armenian_at", 0x1000568),
+    ("Armenian_ayb", 0x1000561),
+    ("Armenian_ben", 0x1000562),
+    ("Armenian_but", 0x100055d),
+    ("Armenian_cha", 0x1000579),
+    ("Armenian_da", 0x1000564),
+    ("Armenian_dza", 0x1000571),
+    ("Armenian_e", 0x1000567),
+    ("Armenian_exclam", 0x100055c),
+    ("Armenian_fe", 0x1000586),
+    ("Armenian_full_stop", 0x1000589),
+    ("Armenian_ghat", 0x1000572),
+    ("Armenian_gim", 0x1000563),
+    ("Armenian_hi", 0x1000575),
+    ("Armenian_ho", 0x1000570),
+    ("Armenian_hyphen", 0x100058a),
+    ("Armenian_ini", 0x100056b),
+    ("Armenian_je", 0x100057b),
+    ("Armenian_ke", 0x1000584),
+    ("Armenian_ken", 0x100056f),
+    ("Armenian_khe", 0x100056d),
+    ("Armenian_ligature_ew", 0x1000587),
+    ("Armenian_lyun", 0x100056c),
+    ("Armenian_men", 0x1000574),
+    ("Armenian_nu", 0x1000576),
+    ("Armenian_o", 0x1000585),
+    ("Armenian_paruyk", 0x100055e),
+    ("Armenian_pe", 0x100057a),
+    ("Armenian_pyur", 0x1000583),
+    ("Armenian_question", 0x100055e),
+    ("Armenian_ra", 0x100057c),
+    ("Armenian_re", 0x1000580),
+    ("Armenian_se", 0x100057d),
+    ("Armenian_separation_mark", 0x100055d),
+    ("Armenian_sha", 0x1000577),
+    ("Armenian_shesht", 0x100055b),
+    ("Armenian_tche", 0x1000573),
+    ("Armenian_to", 0x1000569),
+    ("Armenian_tsa", 0x100056e),
+    ("Armenian_tso", 0x1000581),
+    ("Armenian_tyun", 0x100057f),
+    ("Armenian_verjaket", 0x1000589),
+    ("Armenian_vev", 0x100057e),
+    ("Armenian_vo", 0x1000578),
+    ("Armenian_vyun", 0x1000582),
+    ("Armenian_yech", 0x1000565),
+    ("Armenian_yentamna", 0x100058a),
+    ("Armenian_za", 0x1000566),
+    ("Armenian_zhe", 0x100056a),
+    ("Atilde", 0x00c3),
+    ("AudibleBell_Enable", 0xfe7a),
+    ("B", 0x0042),
+    ("Babovedot", 0x1001e02),
+    ("BackSpace", 0xff08),
+    ("BackTab", 0x1000ff74),
+    ("Begin", 0xff58),
+    ("BounceKeys_Enable", 0xfe74),
+    ("Break", 0xff6b),
+    ("Byelorussian_SHORTU", 0x06be),
+    ("Byelorussian_shortu", 0x06ae),
+    ("C", 0x0043),
+    ("CH", 0xfea2),
+    ("C_H", 0xfea5),
+    ("C_h", 0xfea4),
+    ("Cabovedot", 0x02c5),
+    ("Cacute", 0x01c6),
+    ("Cancel", 0xff69),
+    ("Caps_Lock", 0xffe5),
+    ("Ccaron", 0x01c8),
+    ("Ccedilla", 0x00c7),
+    ("Ccircumflex", 0x02c6),
+    ("Ch", 0xfea1),
+    ("Clear", 0xff0b),
+    ("ClearLine", 0x1000ff6f),
+    ("Codeinput", 0xff37),
+    ("ColonSign", 0x10020a1),
+    ("Control_L", 0xffe3),
+    ("Control_R", 0xffe4),
+    ("CruzeiroSign", 0x10020a2),
+    ("Cyrillic_A", 0x06e1),
+    ("Cyrillic_BE", 0x06e2),
+    ("Cyrillic_CHE", 0x06fe),
+    ("Cyrillic_CHE_descender", 0x10004b6),
+    ("Cyrillic_CHE_vertstroke", 0x10004b8),
+    ("Cyrillic_DE", 0x06e4),
+    ("Cyrillic_DZHE", 0x06bf),
+    ("Cyrillic_E", 0x06fc),
+    ("Cyrillic_EF", 0x06e6),
+    ("Cyrillic_EL", 0x06ec),
+    ("Cyrillic_EM", 0x06ed),
+    ("Cyrillic_EN", 0x06ee),
+    ("Cyrillic_EN_descender", 0x10004a2),
+    ("Cyrillic_ER", 0x06f2),
+    ("Cyrillic_ES", 0x06f3),
+    ("Cyrillic_GHE", 0x06e7),
+    ("Cyrillic_GHE_bar", 0x1000492),
+    ("Cyrillic_HA", 0x06e8),
+    ("Cyrillic_HARDSIGN", 0x06ff),
+    ("Cyrillic_HA_descender", 0x10004b2),
+    ("Cyrillic_I", 0x06e9),
+    ("Cyrillic_IE", 0x06e5),
+    ("Cyrillic_IO", 0x06b3),
+    ("Cyrillic_I_macron", 0x10004e2),
+    ("Cyrillic_JE", 0x06b8),
+    ("Cyrillic_KA", 0x06eb),
+    ("Cyrillic_KA_descender", 0x100049a),
+    ("Cyrillic_KA_vertstroke", 0x100049c),
+    ("Cyrillic_LJE", 0x06b9),
+    ("Cyrillic_NJE", 0x06ba),
+    ("Cyrillic_O", 0x06ef),
+    ("Cyrillic_O_bar", 0x10004e8),
+    ("Cyrillic_PE", 0x06f0),
+    ("Cyrillic_SCHWA", 0x10004d8),
+    ("Cyrillic_SHA", 0x06fb),
+    ("Cyrillic_SHCHA", 0x06fd),
+    ("Cyrillic_SHHA", 0x10004ba),
+    ("Cyrillic_SHORTI", 0x06ea),
+    ("Cyrillic_SOFTSIGN", 0x06f8),
+    ("Cyrillic_TE", 0x06f4),
+    ("Cyrillic_TSE", 0x06e3),
+    ("Cyrillic_U", 0x06f5),
+    ("Cyrillic_U_macron", 0x10004ee),
+    ("Cyrillic_U_straight", 0x10004ae),
+    ("Cyrillic_U_straight_bar", 0x10004b0),
+    ("Cyrillic_VE", 0x06f7),
+    ("Cyrillic_YA", 0x06f1),
+    ("Cyrillic_YERU", 0x06f9),
+    ("Cyrillic_YU", 0x06e0),
+    ("Cyrillic_ZE", 0x06fa),
+    ("Cyrillic_ZHE", 0x06f6),
+    ("Cyrillic_ZHE_descender", 0x1000496),
+    ("Cyrillic_a", 0x06c1),
+    ("Cyrillic_be", 0x06c2),
+    ("Cyrillic_che", 0x06de),
+    ("Cyrillic_che_descender", 0x10004b7),
+    ("Cyrillic_che_vertstroke", 0x10004b9),
+    ("Cyrillic_de", 0x06c4),
+    ("Cyrillic_dzhe", 0x06af),
+    ("Cyrillic_e", 0x06dc),
+    ("Cyrillic_ef", 0x06c6),
+    ("Cyrillic_el", 0x06cc),
+    ("Cyrillic_em", 0x06cd),
+    ("Cyrillic_en", 0x06ce),
+    ("Cyrillic_en_descender", 0x10004a3),
+    ("Cyrillic_er", 0x06d2),
+    ("Cyrillic_es", 0x06d3),
+    ("Cyrillic_ghe", 0x06c7),
+    ("Cyrillic_ghe_bar", 0x1000493),
+    ("Cyrillic_ha", 0x06c8),
+    ("Cyrillic_ha_descender", 0x10004b3),
+    ("Cyrillic_hardsign", 0x06df),
+    ("Cyrillic_i", 0x06c9),
+    ("Cyrillic_i_macron", 0x10004e3),
+    ("Cyrillic_ie", 0x06c5),
+    ("Cyrillic_io", 0x06a3),
+    ("Cyrillic_je", 0x06a8),
+    ("Cyrillic_ka", 0x06cb),
+    ("Cyrillic_ka_descender", 0x100049b),
+    ("Cyrillic_ka_vertstroke", 0x100049d),
+    ("Cyrillic_lje", 0x06a9),
+    ("Cyrillic_nje", 0x06aa),
+    ("Cyrillic_o", 0x06cf),
+    ("Cyrillic_o_bar", 0x10004e9),
+    ("Cyrillic_pe", 0x06d0),
+    ("Cyrillic_schwa", 0x10004d9),
+    ("Cyrillic_sha", 0x06db),
+    ("Cyrillic_shcha", 0x06dd),
+    ("Cyrillic_shha", 0x10004bb),
+    ("Cyrillic_shorti", 0x06ca),
+    ("Cyrillic_softsign", 0x06d8),
+    ("Cyrillic_te", 0x06d4),
+    ("Cyrillic_tse", 0x06c3),
+    ("Cyrillic_u", 0x06d5),
+    ("Cyrillic_u_macron", 0x10004ef),
+    ("Cyrillic_u_straight", 0x10004af),
+    ("Cyrillic_u_straight_bar", 0x10004b1),
+    ("Cyrillic_ve", 0x06d7),
+    ("Cyrillic_ya", 0x06d1),
+    ("Cyrillic_yeru", 0x06d9),
+    ("Cyrillic_yu", 0x06c0),
+    ("Cyrillic_ze", 0x06da),
+    ("Cyrillic_zhe", 0x06d6),
+    ("Cyrillic_zhe_descender", 0x1000497),
+    ("D", 0x0044),
+    ("DRemove", 0x1000ff00),
+    ("Dabovedot", 0x1001e0a),
+    ("Dacute_accent", 0x1000fe27),
+    ("Dcaron", 0x01cf),
+    ("Dcedilla_accent", 0x1000fe2c),
+    ("Dcircumflex_accent", 0x1000fe5e),
+    ("Ddiaeresis", 0x1000fe22),
+    ("Delete", 0xffff),
+    ("DeleteChar", 0x1000ff73),
+    ("DeleteLine", 0x1000ff71),
+    ("Dgrave_accent", 0x1000fe60),
+    ("DongSign", 0x10020ab),
+    ("Down", 0xff54),
+    ("Dring_accent", 0x1000feb0),
+    ("Dstroke", 0x01d0),
+    ("Dtilde", 0x1000fe7e),
+    ("E", 0x0045),
+    ("ENG", 0x03bd),
+    ("ETH", 0x00d0),
+    ("EZH", 0x10001b7),
+    ("Eabovedot", 0x03cc),
+    ("Eacute", 0x00c9),
+    ("Ebelowdot", 0x1001eb8),
+    ("Ecaron", 0x01cc),
+    ("Ecircumflex", 0x00ca),
+    ("Ecircumflexacute", 0x1001ebe),
+    ("Ecircumflexbelowdot", 0x1001ec6),
+    ("Ecircumflexgrave", 0x1001ec0),
+    ("Ecircumflexhook", 0x1001ec2),
+    ("Ecircumflextilde", 0x1001ec4),
+    ("EcuSign", 0x10020a0),
+    ("Ediaeresis", 0x00cb),
+    ("Egrave", 0x00c8),
+    ("Ehook", 0x1001eba),
+    ("Eisu_Shift", 0xff2f),
+    ("Eisu_toggle", 0xff30),
+    ("Emacron", 0x03aa),
+    ("End", 0xff57),
+    ("Eogonek", 0x01ca),
+    ("Escape", 0xff1b),
+    ("Eth", 0x00d0),
+    ("Etilde", 0x1001ebc),
+    ("EuroSign", 0x20ac),
+    ("Execute", 0xff62),
+    ("Ext16bit_L", 0x1000ff76),
+    ("Ext16bit_R", 0x1000ff77),
+    ("F", 0x0046),
+    ("F1", 0xffbe),
+    ("F10", 0xffc7),
+    ("F11", 0xffc8),
+    ("F12", 0xffc9),
+    ("F13", 0xffca),
+    ("F14", 0xffcb),
+    ("F15", 0xffcc),
+    ("F16", 0xffcd),
+    ("F17", 0xffce),
+    ("F18", 0xffcf),
+    ("F19", 0xffd0),
+    ("F2", 0xffbf),
+    ("F20", 0xffd1),
+    ("F21", 0xffd2),
+    ("F22", 0xffd3),
+    ("F23", 0xffd4),
+    ("F24", 0xffd5),
+    ("F25", 0xffd6),
+    ("F26", 0xffd7),
+    ("F27", 0xffd8),
+    ("F28", 0xffd9),
+    ("F29", 0xffda),
+    ("F3", 0xffc0),
+    ("F30", 0xffdb),
+    ("F31", 0xffdc),
+    ("F32", 0xffdd),
+    ("F33", 0xffde),
+    ("F34", 0xffdf),
+    ("F35", 0xffe0),
+    ("F4", 0xffc1),
+    ("F5", 0xffc2),
+    ("F6", 0xffc3),
+    ("F7", 0xffc4),
+    ("F8", 0xffc5),
+    ("F9", 0xffc6),
+    ("FFrancSign", 0x10020a3),
+    ("Fabovedot", 0x1001e1e),
+    ("Farsi_0", 0x10006f0),
+    ("Farsi_1", 0x10006f1),
+    ("Farsi_2", 0x10006f2),
+    ("Farsi_3", 0x10006f3),
+    ("Farsi_4", 0x10006f4),
+    ("Farsi_5", 0x10006f5),
+    ("Farsi_6", 0x10006f6),
+    ("Farsi_7", 0x10006f7),
+    ("Farsi_8", 0x10006f8),
+    ("Farsi_9", 0x10006f9),
+    ("Farsi_yeh", 0x10006cc),
+    ("Find", 0xff68),
+    ("First_Virtual_Screen", 0xfed0),
+    ("G", 0x0047),
+    ("Gabovedot", 0x02d5),
+    ("Gbreve", 0x02ab),
+    ("Gcaron", 0x10001e6),
+    ("Gcedilla", 0x03ab),
+    ("Gcircumflex", 0x02d8),
+    ("Georgian_an", 0x10010d0),
+    ("Georgian_ban", 0x10010d1),
+    ("Georgian_can", 0x10010ea),
+    ("Georgian_char", 0x10010ed),
+    ("Georgian_chin", 0x10010e9),
+    ("Georgian_cil", 0x10010ec),
+    ("Georgian_don", 0x10010d3),
+    ("Georgian_en", 0x10010d4),
+    ("Georgian_fi", 0x10010f6),
+    ("Georgian_gan", 0x10010d2),
+    ("Georgian_ghan", 0x10010e6),
+    ("Georgian_hae", 0x10010f0),
+    ("Georgian_har", 0x10010f4),
+    ("Georgian_he", 0x10010f1),
+    ("Georgian_hie", 0x10010f2),
+    ("Georgian_hoe", 0x10010f5),
+    ("Georgian_in", 0x10010d8),
+    ("Georgian_jhan", 0x10010ef),
+    ("Georgian_jil", 0x10010eb),
+    ("Georgian_kan", 0x10010d9),
+    ("Georgian_khar", 0x10010e5),
+    ("Georgian_las", 0x10010da),
+    ("Georgian_man", 0x10010db),
+    ("Georgian_nar", 0x10010dc),
+    ("Georgian_on", 0x10010dd),
+    ("Georgian_par", 0x10010de),
+    ("Georgian_phar", 0x10010e4),
+    ("Georgian_qar", 0x10010e7),
+    ("Georgian_rae", 0x10010e0),
+    ("Georgian_san", 0x10010e1),
+    ("Georgian_shin", 0x10010e8),
+    ("Georgian_tan", 0x10010d7),
+    ("Georgian_tar", 0x10010e2),
+    ("Georgian_un", 0x10010e3),
+    ("Georgian_vin", 0x10010d5),
+    ("Georgian_we", 0x10010f3),
+    ("Georgian_xan", 0x10010ee),
+    ("Georgian_zen", 0x10010d6),
+    ("Georgian_zhar", 0x10010df),
+    ("Greek_ALPHA", 0x07c1),
+    ("Greek_ALPHAaccent", 0x07a1),
+    ("Greek_BETA", 0x07c2),
+    ("Greek_CHI", 0x07d7),
+    ("Greek_DELTA", 0x07c4),
+    ("Greek_EPSILON", 0x07c5),
+    ("Greek_EPSILONaccent", 0x07a2),
+    ("Greek_ETA", 0x07c7),
+    ("Greek_ETAaccent", 0x07a3),
+    ("Greek_GAMMA", 0x07c3),
+    ("Greek_IOTA", 0x07c9),
+    ("Greek_IOTAaccent", 0x07a4),
+    ("Greek_IOTAdiaeresis", 0x07a5),
+    ("Greek_IOTAdieresis", 0x07a5),
+    ("Greek_KAPPA", 0x07ca),
+    ("Greek_LAMBDA", 0x07cb),
+    ("Greek_LAMDA", 0x07cb),
+    ("Greek_MU", 0x07cc),
+    ("Greek_NU", 0x07cd),
+    ("Greek_OMEGA", 0x07d9),
+    ("Greek_OMEGAaccent", 0x07ab),
+    ("Greek_OMICRON", 0x07cf),
+    ("Greek_OMICRONaccent", 0x07a7),
+    ("Greek_PHI", 0x07d6),
+    ("Greek_PI", 0x07d0),
+    ("Greek_PSI", 0x07d8),
+    ("Greek_RHO", 0x07d1),
+    ("Greek_SIGMA", 0x07d2),
+    ("Greek_TAU", 0x07d4),
+    ("Greek_THETA", 0x07c8),
+    ("Greek_UPSILON", 0x07d5),
+    ("Greek_UPSILONaccent", 0x07a8),
+    ("Greek_UPSILONdieresis", 0x07a9),
+    ("Greek_XI", 0x07ce),
+    ("Greek_ZETA", 0x07c6),
+    ("Greek_accentdieresis", 0x07ae),
+    ("Greek_alpha", 0x07e1),
+    ("Greek_alphaaccent", 0x07b1),
+    ("Greek_beta", 0x07e2),
+    ("Greek_chi", 0x07f7),
+    ("Greek_delta", 0x07e4),
+    ("Greek_epsilon", 0x07e5),
+    ("Greek_epsilonaccent", 0x07b2),
+    ("Greek_eta", 0x07e7),
+    ("Greek_etaaccent", 0x07b3),
+    ("Greek_finalsmallsigma", 0x07f3),
+    ("Greek_gamma", 0x07e3),
+    ("Greek_horizbar", 0x07af),
+    ("Greek_iota", 0x07e9),
+    ("Greek_iotaaccent", 0x07b4),
+    ("Greek_iotaaccentdieresis", 0x07b6),
+    ("Greek_iotadieresis", 0x07b5),
+    ("Greek_kappa", 0x07ea),
+    ("Greek_lambda", 0x07eb),
+    ("Greek_lamda", 0x07eb),
+    ("Greek_mu", 0x07ec),
+    ("Greek_nu", 0x07ed),
+    ("Greek_omega", 0x07f9),
+    ("Greek_omegaaccent", 0x07bb),
+    ("Greek_omicron", 0x07ef),
+    ("Greek_omicronaccent", 0x07b7),
+    ("Greek_phi", 0x07f6),
+    ("Greek_pi", 0x07f0),
+    ("Greek_psi", 0x07f8),
+    ("Greek_rho", 0x07f1),
+    ("Greek_sigma", 0x07f2),
+    ("Greek_switch", 0xff7e),
+    ("Greek_tau", 0x07f4),
+    ("Greek_theta", 0x07e8),
+    ("Greek_upsilon", 0x07f5),
+    ("Greek_upsilonaccent", 0x07b8),
+    ("Greek_upsilonaccentdieresis", 0x07ba),
+    ("Greek_upsilondieresis", 0x07b9),
+    ("Greek_xi", 0x07ee),
+    ("Greek_zeta", 0x07e6),
+    ("H", 0x0048),
+    ("Hangul", 0xff31),
+    ("Hangul_A", 0x0ebf),
+    ("Hangul_AE", 0x0ec0),
+    ("Hangul_AraeA", 0x0ef6),
+    ("Hangul_AraeAE", 0x0ef7),
+    ("Hangul_Banja", 0xff39),
+    ("Hangul_Cieuc", 0x0eba),
+    ("Hangul_Codeinput", 0xff37),
+    ("Hangul_Dikeud", 0x0ea7),
+    ("Hangul_E", 0x0ec4),
+    ("Hangul_EO", 0x0ec3),
+    ("Hangul_EU", 0x0ed1),
+    ("Hangul_End", 0xff33),
+    ("Hangul_Hanja", 0xff34),
+    ("Hangul_Hieuh", 0x0ebe),
+    ("Hangul_I", 0x0ed3),
+    ("Hangul_Ieung", 0x0eb7),
+    ("Hangul_J_Cieuc", 0x0eea),
+    ("Hangul_J_Dikeud", 0x0eda),
+    ("Hangul_J_Hieuh", 0x0eee),
+    ("Hangul_J_Ieung", 0x0ee8),
+    ("Hangul_J_Jieuj", 0x0ee9),
+    ("Hangul_J_Khieuq", 0x0eeb),
+    ("Hangul_J_Kiyeog", 0x0ed4),
+    ("Hangul_J_KiyeogSios", 0x0ed6),
+    ("Hangul_J_KkogjiDalrinIeung", 0x0ef9),
+    ("Hangul_J_Mieum", 0x0ee3),
+    ("Hangul_J_Nieun", 0x0ed7),
+    ("Hangul_J_NieunHieuh", 0x0ed9),
+    ("Hangul_J_NieunJieuj", 0x0ed8),
+    ("Hangul_J_PanSios", 0x0ef8),
+    ("Hangul_J_Phieuf", 0x0eed),
+    ("Hangul_J_Pieub", 0x0ee4),
+    ("Hangul_J_PieubSios", 0x0ee5),
+    ("Hangul_J_Rieul", 0x0edb),
+    ("Hangul_J_RieulHieuh", 0x0ee2),
+    ("Hangul_J_RieulKiyeog", 0x0edc),
+    ("Hangul_J_RieulMieum", 0x0edd),
+    ("Hangul_J_RieulPhieuf", 0x0ee1),
+    ("Hangul_J_RieulPieub", 0x0ede),
+    ("Hangul_J_RieulSios", 0x0edf),
+    ("Hangul_J_RieulTieut", 0x0ee0),
+    ("Hangul_J_Sios", 0x0ee6),
+    ("Hangul_J_SsangKiyeog", 0x0ed5),
+    ("Hangul_J_SsangSios", 0x0ee7),
+    ("Hangul_J_Tieut", 0x0eec),
+    ("Hangul_J_YeorinHieuh", 0x0efa),
+    ("Hangul_Jamo", 0xff35),
+    ("Hangul_Jeonja", 0xff38),
+    ("Hangul_Jieuj", 0x0eb8),
+    ("Hangul_Khieuq", 0x0ebb),
+    ("Hangul_Kiyeog", 0x0ea1),
+    ("Hangul_KiyeogSios", 0x0ea3),
+    ("Hangul_KkogjiDalrinIeung", 0x0ef3),
+    ("Hangul_Mieum", 0x0eb1),
+    ("Hangul_MultipleCandidate", 0xff3d),
+    ("Hangul_Nieun", 0x0ea4),
+    ("Hangul_NieunHieuh", 0x0ea6),
+    ("Hangul_NieunJieuj", 0x0ea5),
+    ("Hangul_O", 0x0ec7),
+    ("Hangul_OE", 0x0eca),
+    ("Hangul_PanSios", 0x0ef2),
+    ("Hangul_Phieuf", 0x0ebd),
+    ("Hangul_Pieub", 0x0eb2),
+    ("Hangul_PieubSios", 0x0eb4),
+    ("Hangul_PostHanja", 0xff3b),
+    ("Hangul_PreHanja", 0xff3a),
+    ("Hangul_PreviousCandidate", 0xff3e),
+    ("Hangul_Rieul", 0x0ea9),
+    ("Hangul_RieulHieuh", 0x0eb0),
+    ("Hangul_RieulKiyeog", 0x0eaa),
+    ("Hangul_RieulMieum", 0x0eab),
+    ("Hangul_RieulPhieuf", 0x0eaf),
+    ("Hangul_RieulPieub", 0x0eac),
+    ("Hangul_RieulSios", 0x0ead),
+    ("Hangul_RieulTieut", 0x0eae),
+    ("Hangul_RieulYeorinHieuh", 0x0eef),
+    ("Hangul_Romaja", 0xff36),
+    ("Hangul_SingleCandidate", 0xff3c),
+    ("Hangul_Sios", 0x0eb5),
+    ("Hangul_Special", 0xff3f),
+    ("Hangul_SsangDikeud", 0x0ea8),
+    ("Hangul_SsangJieuj", 0x0eb9),
+    ("Hangul_SsangKiyeog", 0x0ea2),
+    ("Hangul_SsangPieub", 0x0eb3),
+    ("Hangul_SsangSios", 0x0eb6),
+    ("Hangul_Start", 0xff32),
+    ("Hangul_SunkyeongeumMieum", 0x0ef0),
+    ("Hangul_SunkyeongeumPhieuf", 0x0ef4),
+    ("Hangul_SunkyeongeumPieub", 0x0ef1),
+    ("Hangul_Tieut", 0x0ebc),
+    ("Hangul_U", 0x0ecc),
+    ("Hangul_WA", 0x0ec8),
+    ("Hangul_WAE", 0x0ec9),
+    ("Hangul_WE", 0x0ece),
+    ("Hangul_WEO", 0x0ecd),
+    ("Hangul_WI", 0x0ecf),
+    ("Hangul_YA", 0x0ec1),
+    ("Hangul_YAE", 0x0ec2),
+    ("Hangul_YE", 0x0ec6),
+    ("Hangul_YEO", 0x0ec5),
+    ("Hangul_YI", 0x0ed2),
+    ("Hangul_YO", 0x0ecb),
+    ("Hangul_YU", 0x0ed0),
+    ("Hangul_YeorinHieuh", 0x0ef5),
+    ("Hangul_switch", 0xff7e),
+    ("Hankaku", 0xff29),
+    ("Hcircumflex", 0x02a6),
+    ("Hebrew_switch", 0xff7e),
+    ("Help", 0xff6a),
+    ("Henkan", 0xff23),
+    ("Henkan_Mode", 0xff23),
+    ("Hiragana", 0xff25),
+    ("Hiragana_Katakana", 0xff27),
+    ("Home", 0xff50),
+    ("Hstroke", 0x02a1),
+    ("Hyper_L", 0xffed),
+    ("Hyper_R", 0xffee),
+    ("I", 0x0049),
+    ("IO", 0x100000ee),
+    ("ISO_Center_Object", 0xfe33),
+    ("ISO_Continuous_Underline", 0xfe30),
+    ("ISO_Discontinuous_Underline", 0xfe31),
+    ("ISO_Emphasize", 0xfe32),
+    ("ISO_Enter", 0xfe34),
+    ("ISO_Fast_Cursor_Down", 0xfe2f),
+    ("ISO_Fast_Cursor_Left", 0xfe2c),
+    ("ISO_Fast_Cursor_Right", 0xfe2d),
+    ("ISO_Fast_Cursor_Up", 0xfe2e),
+    ("ISO_First_Group", 0xfe0c),
+    ("ISO_First_Group_Lock", 0xfe0d),
+    ("ISO_Group_Latch", 0xfe06),
+    ("ISO_Group_Lock", 0xfe07),
+    ("ISO_Group_Shift", 0xff7e),
+    ("ISO_Last_Group", 0xfe0e),
+    ("ISO_Last_Group_Lock", 0xfe0f),
+    ("ISO_Left_Tab", 0xfe20),
+    ("ISO_Level2_Latch", 0xfe02),
+    ("ISO_Level3_Latch", 0xfe04),
+    ("ISO_Level3_Lock", 0xfe05),
+    ("ISO_Level3_Shift", 0xfe03),
+    ("ISO_Level5_Latch", 0xfe12),
+    ("ISO_Level5_Lock", 0xfe13),
+    ("ISO_Level5_Shift", 0xfe11),
+    ("ISO_Lock", 0xfe01),
+    ("ISO_Move_Line_Down", 0xfe22),
+    ("ISO_Move_Line_Up", 0xfe21),
+    ("ISO_Next_Group", 0xfe08),
+    ("ISO_Next_Group_Lock", 0xfe09),
+    ("ISO_Partial_Line_Down", 0xfe24),
+    ("ISO_Partial_Line_Up", 0xfe23),
+    ("ISO_Partial_Space_Left", 0xfe25),
+    ("ISO_Partial_Space_Right", 0xfe26),
+    ("ISO_Prev_Group", 0xfe0a),
+    ("ISO_Prev_Group_Lock", 0xfe0b),
+    ("ISO_Release_Both_Margins", 0xfe2b),
+    ("ISO_Release_Margin_Left", 0xfe29),
+    ("ISO_Release_Margin_Right", 0xfe2a),
+    ("ISO_Set_Margin_Left", 0xfe27),
+    ("ISO_Set_Margin_Right", 0xfe28),
+    ("Iabovedot", 0x02a9),
+    ("Iacute", 0x00cd),
+    ("Ibelowdot", 0x1001eca),
+    ("Ibreve", 0x100012c),
+    ("Icircumflex", 0x00ce),
+    ("Idiaeresis", 0x00cf),
+    ("Igrave", 0x00cc),
+    ("Ihook", 0x1001ec8),
+    ("Imacron", 0x03cf),
+    ("Insert", 0xff63),
+    ("InsertChar", 0x1000ff72),
+    ("InsertLine", 0x1000ff70),
+    ("Iogonek", 0x03c7),
+    ("Itilde", 0x03a5),
+    ("J", 0x004a),
+    ("Jcircumflex", 0x02ac),
+    ("K", 0x004b),
+    ("KP_0", 0xffb0),
+    ("KP_1", 0xffb1),
+    ("KP_2", 0xffb2),
+    ("KP_3", 0xffb3),
+    ("KP_4", 0xffb4),
+    ("KP_5", 0xffb5),
+    ("KP_6", 0xffb6),
+    ("KP_7", 0xffb7),
+    ("KP_8", 0xffb8),
+    ("KP_9", 0xffb9),
+    ("KP_Add", 0xffab),
+    ("KP_BackTab", 0x1000ff75),
+    ("KP_Begin", 0xff9d),
+    ("KP_Decimal", 0xffae),
+    ("KP_Delete", 0xff9f),
+    ("KP_Divide", 0xffaf),
+    ("KP_Down", 0xff99),
+    ("KP_End", 0xff9c),
+    ("KP_Enter", 0xff8d),
+    ("KP_Equal", 0xffbd),
+    ("KP_F1", 0xff91),
+    ("KP_F2", 0xff92),
+    ("KP_F3", 0xff93),
+    ("KP_F4", 0xff94),
+    ("KP_Home", 0xff95),
+    ("KP_Insert", 0xff9e),
+    ("KP_Left", 0xff96),
+    ("KP_Multiply", 0xffaa),
+    ("KP_Next", 0xff9b),
+    ("KP_Page_Down", 0xff9b),
+    ("KP_Page_Up", 0xff9a),
+    ("KP_Prior", 0xff9a),
+    ("KP_Right", 0xff98),
+    ("KP_Separator", 0xffac),
+    ("KP_Space", 0xff80),
+    ("KP_Subtract", 0xffad),
+    ("KP_Tab", 0xff89),
+    ("KP_Up", 0xff97),
+    ("Kana_Lock", 0xff2d),
+    ("Kana_Shift", 0xff2e),
+    ("Kanji", 0xff21),
+    ("Kanji_Bangou", 0xff37),
+    ("Katakana", 0xff26),
+    ("Kcedilla", 0x03d3),
+    ("Korean_Won", 0x0eff),
+    ("L", 0x004c),
+    ("L1", 0xffc8),
+    ("L10", 0xffd1),
+    ("L2", 0xffc9),
+    ("L3", 0xffca),
+    ("L4", 0xffcb),
+    ("L5", 0xffcc),
+    ("L6", 0xffcd),
+    ("L7", 0xffce),
+    ("L8", 0xffcf),
+    ("L9", 0xffd0),
+    ("Lacute", 0x01c5),
+    ("Last_Virtual_Screen", 0xfed4),
+    ("Lbelowdot", 0x1001e36),
+    ("Lcaron", 0x01a5),
+    ("Lcedilla", 0x03a6),
+    ("Left", 0xff51),
+    ("Linefeed", 0xff0a),
+    ("LiraSign", 0x10020a4),
+    ("Lstroke", 0x01a3),
+    ("M", 0x004d),
+    ("Mabovedot", 0x1001e40),
+    ("Macedonia_DSE", 0x06b5),
+    ("Macedonia_GJE", 0x06b2),
+    ("Macedonia_KJE", 0x06bc),
+    ("Macedonia_dse", 0x06a5),
+    ("Macedonia_gje", 0x06a2),
+    ("Macedonia_kje", 0x06ac),
+    ("Mae_Koho", 0xff3e),
+    ("Massyo", 0xff2c),
+    ("Menu", 0xff67),
+    ("Meta_L", 0xffe7),
+    ("Meta_R", 0xffe8),
+    ("MillSign", 0x10020a5),
+    ("Mode_switch", 0xff7e),
+    ("MouseKeys_Accel_Enable", 0xfe77),
+    ("MouseKeys_Enable", 0xfe76),
+    ("Muhenkan", 0xff22),
+    ("Multi_key", 0xff20),
+    ("MultipleCandidate", 0xff3d),
+    ("N", 0x004e),
+    ("Nacute", 0x01d1),
+    ("NairaSign", 0x10020a6),
+    ("Ncaron", 0x01d2),
+    ("Ncedilla", 0x03d1),
+    ("NewSheqelSign", 0x10020aa),
+    ("Next", 0xff56),
+    ("Next_Virtual_Screen", 0xfed2),
+    ("Ntilde", 0x00d1),
+    ("Num_Lock", 0xff7f),
+    ("O", 0x004f),
+    ("OE", 0x13bc),
+    ("Oacute", 0x00d3),
+    ("Obarred", 0x100019f),
+    ("Obelowdot", 0x1001ecc),
+    ("Ocaron", 0x10001d1),
+    ("Ocircumflex", 0x00d4),
+    ("Ocircumflexacute", 0x1001ed0),
+    ("Ocircumflexbelowdot", 0x1001ed8),
+    ("Ocircumflexgrave", 0x1001ed2),
+    ("Ocircumflexhook", 0x1001ed4),
+    ("Ocircumflextilde", 0x1001ed6),
+    ("Odiaeresis", 0x00d6),
+    ("Odoubleacute", 0x01d5),
+    ("Ograve", 0x00d2),
+    ("Ohook", 0x1001ece),
+    ("Ohorn", 0x10001a0),
+    ("Ohornacute", 0x1001eda),
+    ("Ohornbelowdot", 0x1001ee2),
+    ("Ohorngrave", 0x1001edc),
+    ("Ohornhook", 0x1001ede),
+    ("Ohorntilde", 0x1001ee0),
+    ("Omacron", 0x03d2),
+    ("Ooblique", 0x00d8),
+    ("Oslash", 0x00d8),
+    ("Otilde", 0x00d5),
+    ("Overlay1_Enable", 0xfe78),
+    ("Overlay2_Enable", 0xfe79),
+    ("P", 0x0050),
+    ("Pabovedot", 0x1001e56),
+    ("Page_Down", 0xff56),
+    ("Page_Up", 0xff55),
+    ("Pause", 0xff13),
+    ("PesetaSign", 0x10020a7),
+    ("Pointer_Accelerate", 0xfefa),
+    ("Pointer_Button1", 0xfee9),
+    ("Pointer_Button2", 0xfeea),
+    ("Pointer_Button3", 0xfeeb),
+    ("Pointer_Button4", 0xfeec),
+    ("Pointer_Button5", 0xfeed),
+    ("Pointer_Button_Dflt", 0xfee8),
+    ("Pointer_DblClick1", 0xfeef),
+    ("Pointer_DblClick2", 0xfef0),
+    ("Pointer_DblClick3", 0xfef1),
+    ("Pointer_DblClick4", 0xfef2),
+    ("Pointer_DblClick5", 0xfef3),
+    ("Pointer_DblClick_Dflt", 0xfeee),
+    ("Pointer_DfltBtnNext", 0xfefb),
+    ("Pointer_DfltBtnPrev", 0xfefc),
+    ("Pointer_Down", 0xfee3),
+    ("Pointer_DownLeft", 0xfee6),
+    ("Pointer_DownRight", 0xfee7),
+    ("Pointer_Drag1", 0xfef5),
+    ("Pointer_Drag2", 0xfef6),
+    ("Pointer_Drag3", 0xfef7),
+    ("Pointer_Drag4", 0xfef8),
+    ("Pointer_Drag5", 0xfefd),
+    ("Pointer_Drag_Dflt", 0xfef4),
+    ("Pointer_EnableKeys", 0xfef9),
+    ("Pointer_Left", 0xfee0),
+    ("Pointer_Right", 0xfee1),
+    ("Pointer_Up", 0xfee2),
+    ("Pointer_UpLeft", 0xfee4),
+    ("Pointer_UpRight", 0xfee5),
+    ("Prev_Virtual_Screen", 0xfed1),
+    ("PreviousCandidate", 0xff3e),
+    ("Print", 0xff61),
+    ("Prior", 0xff55),
+    ("Q", 0x0051),
+    ("R", 0x0052),
+    ("R1", 0xffd2),
+    ("R10", 0xffdb),
+    ("R11", 0xffdc),
+    ("R12", 0xffdd),
+    ("R13", 0xffde),
+    ("R14", 0xffdf),
+    ("R15", 0xffe0),
+    ("R2", 0xffd3),
+    ("R3", 0xffd4),
+    ("R4", 0xffd5),
+    ("R5", 0xffd6),
+    ("R6", 0xffd7),
+    ("R7", 0xffd8),
+    ("R8", 0xffd9),
+    ("R9", 0xffda),
+    ("Racute", 0x01c0),
+    ("Rcaron", 0x01d8),
+    ("Rcedilla", 0x03a3),
+    ("Redo", 0xff66),
+    ("RepeatKeys_Enable", 0xfe72),
+    ("Reset", 0x1000ff6c),
+    ("Return", 0xff0d),
+    ("Right", 0xff53),
+    ("Romaji", 0xff24),
+    ("RupeeSign", 0x10020a8),
+    ("S", 0x0053),
+    ("SCHWA", 0x100018f),
+    ("Sabovedot", 0x1001e60),
+    ("Sacute", 0x01a6),
+    ("Scaron", 0x01a9),
+    ("Scedilla", 0x01aa),
+    ("Scircumflex", 0x02de),
+    ("Scroll_Lock", 0xff14),
+    ("Select", 0xff60),
+    ("Serbian_DJE", 0x06b1),
+    ("Serbian_DZE", 0x06bf),
+    ("Serbian_JE", 0x06b8),
+    ("Serbian_LJE", 0x06b9),
+    ("Serbian_NJE", 0x06ba),
+    ("Serbian_TSHE", 0x06bb),
+    ("Serbian_dje", 0x06a1),
+    ("Serbian_dze", 0x06af),
+    ("Serbian_je", 0x06a8),
+    ("Serbian_lje", 0x06a9),
+    ("Serbian_nje", 0x06aa),
+    ("Serbian_tshe", 0x06ab),
+    ("Shift_L", 0xffe1),
+    ("Shift_Lock", 0xffe6),
+    ("Shift_R", 0xffe2),
+    ("SingleCandidate", 0xff3c),
+    ("Sinh_a", 0x1000d85),
+    ("Sinh_aa", 0x1000d86),
+    ("Sinh_aa2", 0x1000dcf),
+    ("Sinh_ae", 0x1000d87),
+    ("Sinh_ae2", 0x1000dd0),
+    ("Sinh_aee", 0x1000d88),
+    ("Sinh_aee2", 0x1000dd1),
+    ("Sinh_ai", 0x1000d93),
+    ("Sinh_ai2", 0x1000ddb),
+    ("Sinh_al", 0x1000dca),
+    ("Sinh_au", 0x1000d96),
+    ("Sinh_au2", 0x1000dde),
+    ("Sinh_ba", 0x1000db6),
+    ("Sinh_bha", 0x1000db7),
+    ("Sinh_ca", 0x1000da0),
+    ("Sinh_cha", 0x1000da1),
+    ("Sinh_dda", 0x1000da9),
+    ("Sinh_ddha", 0x1000daa),
+    ("Sinh_dha", 0x1000daf),
+    ("Sinh_dhha", 0x1000db0),
+    ("Sinh_e", 0x1000d91),
+    ("Sinh_e2", 0x1000dd9),
+    ("Sinh_ee", 0x1000d92),
+    ("Sinh_ee2", 0x1000dda),
+    ("Sinh_fa", 0x1000dc6),
+    ("Sinh_ga", 0x1000d9c),
+    ("Sinh_gha", 0x1000d9d),
+    ("Sinh_h2", 0x1000d83),
+    ("Sinh_ha", 0x1000dc4),
+    ("Sinh_i", 0x1000d89),
+    ("Sinh_i2", 0x1000dd2),
+    ("Sinh_ii", 0x1000d8a),
+    ("Sinh_ii2", 0x1000dd3),
+    ("Sinh_ja", 0x1000da2),
+    ("Sinh_jha", 0x1000da3),
+    ("Sinh_jnya", 0x1000da5),
+    ("Sinh_ka", 0x1000d9a),
+    ("Sinh_kha", 0x1000d9b),
+    ("Sinh_kunddaliya", 0x1000df4),
+    ("Sinh_la", 0x1000dbd),
+    ("Sinh_lla", 0x1000dc5),
+    ("Sinh_lu", 0x1000d8f),
+    ("Sinh_lu2", 0x1000ddf),
+    ("Sinh_luu", 0x1000d90),
+    ("Sinh_luu2", 0x1000df3),
+    ("Sinh_ma", 0x1000db8),
+    ("Sinh_mba", 0x1000db9),
+    ("Sinh_na", 0x1000db1),
+    ("Sinh_ndda", 0x1000dac),
+    ("Sinh_ndha", 0x1000db3),
+    ("Sinh_ng", 0x1000d82),
+    ("Sinh_ng2", 0x1000d9e),
+    ("Sinh_nga", 0x1000d9f),
+    ("Sinh_nja", 0x1000da6),
+    ("Sinh_nna", 0x1000dab),
+    ("Sinh_nya", 0x1000da4),
+    ("Sinh_o", 0x1000d94),
+    ("Sinh_o2", 0x1000ddc),
+    ("Sinh_oo", 0x1000d95),
+    ("Sinh_oo2", 0x1000ddd),
+    ("Sinh_pa", 0x1000db4),
+    ("Sinh_pha", 0x1000db5),
+    ("Sinh_ra", 0x1000dbb),
+    ("Sinh_ri", 0x1000d8d),
+    ("Sinh_rii", 0x1000d8e),
+    ("Sinh_ru2", 0x1000dd8),
+    ("Sinh_ruu2", 0x1000df2),
+    ("Sinh_sa", 0x1000dc3),
+    ("Sinh_sha", 0x1000dc1),
+    ("Sinh_ssha", 0x1000dc2),
+    ("Sinh_tha", 0x1000dad),
+    ("Sinh_thha", 0x1000dae),
+    ("Sinh_tta", 0x1000da7),
+    ("Sinh_ttha", 0x1000da8),
+    ("Sinh_u", 0x1000d8b),
+    ("Sinh_u2", 0x1000dd4),
+    ("Sinh_uu", 0x1000d8c),
+    ("Sinh_uu2", 0x1000dd6),
+    ("Sinh_va", 0x1000dc0),
+    ("Sinh_ya", 0x1000dba),
+    ("SlowKeys_Enable", 0xfe73),
+    ("StickyKeys_Enable", 0xfe75),
+    ("SunAgain", 0xff66),
+    ("SunAltGraph", 0xff7e),
+    ("SunAudioLowerVolume", 0x1005ff77),
+    ("SunAudioMute", 0x1005ff78),
+    ("SunAudioRaiseVolume", 0x1005ff79),
+    ("SunCompose", 0xff20),
+    ("SunCopy", 0x1005ff72),
+    ("SunCut", 0x1005ff75),
+    ("SunF36", 0x1005ff10),
+    ("SunF37", 0x1005ff11),
+    ("SunFA_Acute", 0x1005ff03),
+    ("SunFA_Cedilla", 0x1005ff05),
+    ("SunFA_Circum", 0x1005ff01),
+    ("SunFA_Diaeresis", 0x1005ff04),
+    ("SunFA_Grave", 0x1005ff00),
+    ("SunFA_Tilde", 0x1005ff02),
+    ("SunFind", 0xff68),
+    ("SunFront", 0x1005ff71),
+    ("SunOpen", 0x1005ff73),
+    ("SunPageDown", 0xff56),
+    ("SunPageUp", 0xff55),
+    ("SunPaste", 0x1005ff74),
+    ("SunPowerSwitch", 0x1005ff76),
+    ("SunPowerSwitchShift", 0x1005ff7d),
+    ("SunPrint_Screen", 0xff61),
+    ("SunProps", 0x1005ff70),
+    ("SunStop", 0xff69),
+    ("SunSys_Req", 0x1005ff60),
+    ("SunUndo", 0xff65),
+    ("SunVideoDegauss", 0x1005ff7a),
+    ("SunVideoLowerBrightness", 0x1005ff7b),
+    ("SunVideoRaiseBrightness", 0x1005ff7c),
+    ("Super_L", 0xffeb),
+    ("Super_R", 0xffec),
+    ("Sys_Req", 0xff15),
+    ("System", 0x1000ff6d),
+    ("T", 0x0054),
+    ("THORN", 0x00de),
+    ("Tab", 0xff09),
+    ("Tabovedot", 0x1001e6a),
+    ("Tcaron", 0x01ab),
+    ("Tcedilla", 0x01de),
+    ("Terminate_Server", 0xfed5),
+    ("Thai_baht", 0x0ddf),
+    ("Thai_bobaimai", 0x0dba),
+    ("Thai_chochan", 0x0da8),
+    ("Thai_chochang", 0x0daa),
+    ("Thai_choching", 0x0da9),
+    ("Thai_chochoe", 0x0dac),
+    ("Thai_dochada", 0x0dae),
+    ("Thai_dodek", 0x0db4),
+    ("Thai_fofa", 0x0dbd),
+    ("Thai_fofan", 0x0dbf),
+    ("Thai_hohip", 0x0dcb),
+    ("Thai_honokhuk", 0x0dce),
+    ("Thai_khokhai", 0x0da2),
+    ("Thai_khokhon", 0x0da5),
+    ("Thai_khokhuat", 0x0da3),
+    ("Thai_khokhwai", 0x0da4),
+    ("Thai_khorakhang", 0x0da6),
+    ("Thai_kokai", 0x0da1),
+    ("Thai_lakkhangyao", 0x0de5),
+    ("Thai_lekchet", 0x0df7),
+    ("Thai_lekha", 0x0df5),
+    ("Thai_lekhok", 0x0df6),
+    ("Thai_lekkao", 0x0df9),
+    ("Thai_leknung", 0x0df1),
+    ("Thai_lekpaet", 0x0df8),
+    ("Thai_leksam", 0x0df3),
+    ("Thai_leksi", 0x0df4),
+    ("Thai_leksong", 0x0df2),
+    ("Thai_leksun", 0x0df0),
+    ("Thai_lochula", 0x0dcc),
+    ("Thai_loling", 0x0dc5),
+    ("Thai_lu", 0x0dc6),
+    ("Thai_maichattawa", 0x0deb),
+    ("Thai_maiek", 0x0de8),
+    ("Thai_maihanakat", 0x0dd1),
+    ("Thai_maihanakat_maitho", 0x0dde),
+    ("Thai_maitaikhu", 0x0de7),
+    ("Thai_maitho", 0x0de9),
+    ("Thai_maitri", 0x0dea),
+    ("Thai_maiyamok", 0x0de6),
+    ("Thai_moma", 0x0dc1),
+    ("Thai_ngongu", 0x0da7),
+    ("Thai_nikhahit", 0x0ded),
+    ("Thai_nonen", 0x0db3),
+    ("Thai_nonu", 0x0db9),
+    ("Thai_oang", 0x0dcd),
+    ("Thai_paiyannoi", 0x0dcf),
+    ("Thai_phinthu", 0x0dda),
+    ("Thai_phophan", 0x0dbe),
+    ("Thai_phophung", 0x0dbc),
+    ("Thai_phosamphao", 0x0dc0),
+    ("Thai_popla", 0x0dbb),
+    ("Thai_rorua", 0x0dc3),
+    ("Thai_ru", 0x0dc4),
+    ("Thai_saraa", 0x0dd0),
+    ("Thai_saraaa", 0x0dd2),
+    ("Thai_saraae", 0x0de1),
+    ("Thai_saraaimaimalai", 0x0de4),
+    ("Thai_saraaimaimuan", 0x0de3),
+    ("Thai_saraam", 0x0dd3),
+    ("Thai_sarae", 0x0de0),
+    ("Thai_sarai", 0x0dd4),
+    ("Thai_saraii", 0x0dd5),
+    ("Thai_sarao", 0x0de2),
+    ("Thai_sarau", 0x0dd8),
+    ("Thai_saraue", 0x0dd6),
+    ("Thai_sarauee", 0x0dd7),
+    ("Thai_sarauu", 0x0dd9),
+    ("Thai_sorusi", 0x0dc9),
+    ("Thai_sosala", 0x0dc8),
+    ("Thai_soso", 0x0dab),
+    ("Thai_sosua", 0x0dca),
+    ("Thai_thanthakhat", 0x0dec),
+    ("Thai_thonangmontho", 0x0db1),
+    ("Thai_thophuthao", 0x0db2),
+    ("Thai_thothahan", 0x0db7),
+    ("Thai_thothan", 0x0db0),
+    ("Thai_thothong", 0x0db8),
+    ("Thai_thothung", 0x0db6),
+    ("Thai_topatak", 0x0daf),
+    ("Thai_totao", 0x0db5),
+    ("Thai_wowaen", 0x0dc7),
+    ("Thai_yoyak", 0x0dc2),
+    ("Thai_yoying", 0x0dad),
+    ("Thorn", 0x00de),
+    ("Touroku", 0xff2b),
+    ("Tslash", 0x03ac),
+    ("U", 0x0055),
+    ("Uacute", 0x00da),
+    ("Ubelowdot", 0x1001ee4),
+    ("Ubreve", 0x02dd),
+    ("Ucircumflex", 0x00db),
+    ("Udiaeresis", 0x00dc),
+    ("Udoubleacute", 0x01db),
+    ("Ugrave", 0x00d9),
+    ("Uhook", 0x1001ee6),
+    ("Uhorn", 0x10001af),
+    ("Uhornacute", 0x1001ee8),
+    ("Uhornbelowdot", 0x1001ef0),
+    ("Uhorngrave", 0x1001eea),
+    ("Uhornhook", 0x1001eec),
+    ("Uhorntilde", 0x1001eee),
+    ("Ukrainian_GHE_WITH_UPTURN", 0x06bd),
+    ("Ukrainian_I", 0x06b6),
+    ("Ukrainian_IE", 0x06b4),
+    ("Ukrainian_YI", 0x06b7),
+    ("Ukrainian_ghe_with_upturn", 0x06ad),
+    ("Ukrainian_i", 0x06a6),
+    ("Ukrainian_ie", 0x06a4),
+    ("Ukrainian_yi", 0x06a7),
+    ("Ukranian_I", 0x06b6),
+    ("Ukranian_JE", 0x06b4),
+    ("Ukranian_YI", 0x06b7),
+    ("Ukranian_i", 0x06a6),
+    ("Ukranian_je", 0x06a4),
+    ("Ukranian_yi", 0x06a7),
+    ("Umacron", 0x03de),
+    ("Undo", 0xff65),
+    ("Uogonek", 0x03d9),
+    ("Up", 0xff52),
+    ("Uring", 0x01d9),
+    ("User", 0x1000ff6e),
+    ("Utilde", 0x03dd),
+    ("V", 0x0056),
+    ("VoidSymbol", 0xffffff),
+    ("W", 0x0057),
+    ("Wacute", 0x1001e82),
+    ("Wcircumflex", 0x1000174),
+    ("Wdiaeresis", 0x1001e84),
+    ("Wgrave", 0x1001e80),
+    ("WonSign", 0x10020a9),
+    ("X", 0x0058),
+    ("XF8610ChannelsDown", 0x100811b9),
+    ("XF8610ChannelsUp", 0x100811b8),
+    ("XF863DMode", 0x1008126f),
+    ("XF86ALSToggle", 0x10081230),
+    ("XF86AddFavorite", 0x1008ff39),
+    ("XF86Addressbook", 0x100811ad),
+    ("XF86AppSelect", 0x10081244),
+    ("XF86ApplicationLeft", 0x1008ff50),
+    ("XF86ApplicationRight", 0x1008ff51),
+    ("XF86AspectRatio", 0x10081177),
+    ("XF86Assistant", 0x10081247),
+    ("XF86AttendantOff", 0x1008121c),
+    ("XF86AttendantOn", 0x1008121b),
+    ("XF86AttendantToggle", 0x1008121d),
+    ("XF86Audio", 0x10081188),
+    ("XF86AudioCycleTrack", 0x1008ff9b),
+    ("XF86AudioDesc", 0x1008126e),
+    ("XF86AudioForward", 0x1008ff97),
+    ("XF86AudioLowerVolume", 0x1008ff11),
+    ("XF86AudioMedia", 0x1008ff32),
+    ("XF86AudioMicMute", 0x1008ffb2),
+    ("XF86AudioMute", 0x1008ff12),
+    ("XF86AudioNext", 0x1008ff17),
+    ("XF86AudioPause", 0x1008ff31),
+    ("XF86AudioPlay", 0x1008ff14),
+    ("XF86AudioPreset", 0x1008ffb6),
+    ("XF86AudioPrev", 0x1008ff16),
+    ("XF86AudioRaiseVolume", 0x1008ff13),
+    ("XF86AudioRandomPlay", 0x1008ff99),
+    ("XF86AudioRecord", 0x1008ff1c),
+    ("XF86AudioRepeat", 0x1008ff98),
+    ("XF86AudioRewind", 0x1008ff3e),
+    ("XF86AudioStop", 0x1008ff15),
+    ("XF86Away", 0x1008ff8d),
+    ("XF86Back", 0x1008ff26),
+    ("XF86BackForward", 0x1008ff3f),
+    ("XF86Battery", 0x1008ff93),
+    ("XF86Blue", 0x1008ffa6),
+    ("XF86Bluetooth", 0x1008ff94),
+    ("XF86Book", 0x1008ff52),
+    ("XF86Break", 0x1008119b),
+    ("XF86BrightnessAdjust", 0x1008ff3b),
+    ("XF86BrightnessAuto", 0x100810f4),
+    ("XF86BrightnessMax", 0x10081251),
+    ("XF86BrightnessMin", 0x10081250),
+    ("XF86Buttonconfig", 0x10081240),
+    ("XF86CD", 0x1008ff53),
+    ("XF86Calculater", 0x1008ff54),
+    ("XF86Calculator", 0x1008ff1d),
+    ("XF86Calendar", 0x1008ff20),
+    ("XF86CameraDown", 0x10081218),
+    ("XF86CameraFocus", 0x10081210),
+    ("XF86CameraLeft", 0x10081219),
+    ("XF86CameraRight", 0x1008121a),
+    ("XF86CameraUp", 0x10081217),
+    ("XF86CameraZoomIn", 0x10081215),
+    ("XF86CameraZoomOut", 0x10081216),
+    ("XF86ChannelDown", 0x10081193),
+    ("XF86ChannelUp", 0x10081192),
+    ("XF86Clear", 0x1008ff55),
+    ("XF86ClearGrab", 0x1008fe21),
+    ("XF86Close", 0x1008ff56),
+    ("XF86Community", 0x1008ff3d),
+    ("XF86ContextMenu", 0x100811b6),
+    ("XF86ContrastAdjust", 0x1008ff22),
+    ("XF86ControlPanel", 0x10081243),
+    ("XF86Copy", 0x1008ff57),
+    ("XF86Cut", 0x1008ff58),
+    ("XF86CycleAngle", 0x1008ff9c),
+    ("XF86DOS", 0x1008ff5a),
+    ("XF86DVD", 0x10081185),
+    ("XF86Data", 0x10081277),
+    ("XF86Database", 0x100811aa),
+    ("XF86Dictate", 0x1008124a),
+    ("XF86Display", 0x1008ff59),
+    ("XF86DisplayOff", 0x100810f5),
+    ("XF86DisplayToggle", 0x100811af),
+    ("XF86Documents", 0x1008ff5b),
+    ("XF86Editor", 0x100811a6),
+    ("XF86Eject", 0x1008ff2c),
+    ("XF86EmojiPicker", 0x10081249),
+    ("XF86Excel", 0x1008ff5c),
+    ("XF86Explorer", 0x1008ff5d),
+    ("XF86FastReverse", 0x10081275),
+    ("XF86Favorites", 0x1008ff30),
+    ("XF86Finance", 0x1008ff3c),
+    ("XF86Fn", 0x100811d0),
+    ("XF86FnRightShift", 0x100811e5),
+    ("XF86Fn_Esc", 0x100811d1),
+    ("XF86Forward", 0x1008ff27),
+    ("XF86FrameBack", 0x1008ff9d),
+    ("XF86FrameForward", 0x1008ff9e),
+    ("XF86FullScreen", 0x1008ffb8),
+    ("XF86Game", 0x1008ff5e),
+    ("XF86Go", 0x1008ff5f),
+    ("XF86GraphicsEditor", 0x100811a8),
+    ("XF86Green", 0x1008ffa4),
+    ("XF86HangupPhone", 0x100811be),
+    ("XF86Hibernate", 0x1008ffa8),
+    ("XF86History", 0x1008ff37),
+    ("XF86HomePage", 0x1008ff18),
+    ("XF86HotLinks", 0x1008ff3a),
+    ("XF86Images", 0x100811ba),
+    ("XF86Info", 0x10081166),
+    ("XF86Journal", 0x10081242),
+    ("XF86KbdBrightnessDown", 0x1008ff06),
+    ("XF86KbdBrightnessUp", 0x1008ff05),
+    ("XF86KbdInputAssistAccept", 0x10081264),
+    ("XF86KbdInputAssistCancel", 0x10081265),
+    ("XF86KbdInputAssistNext", 0x10081261),
+    ("XF86KbdInputAssistNextgroup", 0x10081263),
+    ("XF86KbdInputAssistPrev", 0x10081260),
+    ("XF86KbdInputAssistPrevgroup", 0x10081262),
+    ("XF86KbdLcdMenu1", 0x100812b8),
+    ("XF86KbdLcdMenu2", 0x100812b9),
+    ("XF86KbdLcdMenu3", 0x100812ba),
+    ("XF86KbdLcdMenu4", 0x100812bb),
+    ("XF86KbdLcdMenu5", 0x100812bc),
+    ("XF86KbdLightOnOff", 0x1008ff04),
+    ("XF86Keyboard", 0x1008ffb3),
+    ("XF86Launch0", 0x1008ff40),
+    ("XF86Launch1", 0x1008ff41),
+    ("XF86Launch2", 0x1008ff42),
+    ("XF86Launch3", 0x1008ff43),
+    ("XF86Launch4", 0x1008ff44),
+    ("XF86Launch5", 0x1008ff45),
+    ("XF86Launch6", 0x1008ff46),
+    ("XF86Launch7", 0x1008ff47),
+    ("XF86Launch8", 0x1008ff48),
+    ("XF86Launch9", 0x1008ff49),
+    ("XF86LaunchA", 0x1008ff4a),
+    ("XF86LaunchB", 0x1008ff4b),
+    ("XF86LaunchC", 0x1008ff4c),
+    ("XF86LaunchD", 0x1008ff4d),
+    ("XF86LaunchE", 0x1008ff4e),
+    ("XF86LaunchF", 0x1008ff4f),
+    ("XF86LeftDown", 0x10081269),
+    ("XF86LeftUp", 0x10081268),
+    ("XF86LightBulb", 0x1008ff35),
+    ("XF86LightsToggle", 0x1008121e),
+    ("XF86LogGrabInfo", 0x1008fe25),
+    ("XF86LogOff", 0x1008ff61),
+    ("XF86LogWindowTree", 0x1008fe24),
+    ("XF86Macro1", 0x10081290),
+    ("XF86Macro10", 0x10081299),
+    ("XF86Macro11", 0x1008129a),
+    ("XF86Macro12", 0x1008129b),
+    ("XF86Macro13", 0x1008129c),
+    ("XF86Macro14", 0x1008129d),
+    ("XF86Macro15", 0x1008129e),
+    ("XF86Macro16", 0x1008129f),
+    ("XF86Macro17", 0x100812a0),
+    ("XF86Macro18", 0x100812a1),
+    ("XF86Macro19", 0x100812a2),
+    ("XF86Macro2", 0x10081291),
+    ("XF86Macro20", 0x100812a3),
+    ("XF86Macro21", 0x100812a4),
+    ("XF86Macro22", 0x100812a5),
+    ("XF86Macro23", 0x100812a6),
+    ("XF86Macro24", 0x100812a7),
+    ("XF86Macro25", 0x100812a8),
+    ("XF86Macro26", 0x100812a9),
+    ("XF86Macro27", 0x100812aa),
+    ("XF86Macro28", 0x100812ab),
+    ("XF86Macro29", 0x100812ac),
+    ("XF86Macro3", 0x10081292),
+    ("XF86Macro30", 0x100812ad),
+    ("XF86Macro4", 0x10081293),
+    ("XF86Macro5", 0x10081294),
+    ("XF86Macro6", 0x10081295),
+    ("XF86Macro7", 0x10081296),
+    ("XF86Macro8", 0x10081297),
+    ("XF86Macro9", 0x10081298),
+    ("XF86MacroPreset1", 0x100812b3),
+    ("XF86MacroPreset2", 0x100812b4),
+    ("XF86MacroPreset3", 0x100812b5),
+    ("XF86MacroPresetCycle", 0x100812b2),
+    ("XF86MacroRecordStart", 0x100812b0),
+    ("XF86MacroRecordStop", 0x100812b1),
+    ("XF86Mail", 0x1008ff19),
+    ("XF86MailForward", 0x1008ff90),
+    ("XF86Market", 0x1008ff62),
+    ("XF86MediaRepeat", 0x100811b7),
+    ("XF86MediaTopMenu", 0x1008126b),
+    ("XF86Meeting", 0x1008ff63),
+    ("XF86Memo", 0x1008ff1e),
+    ("XF86MenuKB", 0x1008ff65),
+    ("XF86MenuPB", 0x1008ff66),
+    ("XF86Messenger", 0x1008ff8e),
+    ("XF86ModeLock", 0x1008ff01),
+    ("XF86MonBrightnessCycle", 0x1008ff07),
+    ("XF86MonBrightnessDown", 0x1008ff03),
+    ("XF86MonBrightnessUp", 0x1008ff02),
+    ("XF86Music", 0x1008ff92),
+    ("XF86MyComputer", 0x1008ff33),
+    ("XF86MySites", 0x1008ff67),
+    ("XF86New", 0x1008ff68),
+    ("XF86News", 0x1008ff69),
+    ("XF86NextFavorite", 0x10081270),
+    ("XF86Next_VMode", 0x1008fe22),
+    ("XF86NotificationCenter", 0x100811bc),
+    ("XF86Numeric0", 0x10081200),
+    ("XF86Numeric1", 0x10081201),
+    ("XF86Numeric11", 0x1008126c),
+    ("XF86Numeric12", 0x1008126d),
+    ("XF86Numeric2", 0x10081202),
+    ("XF86Numeric3", 0x10081203),
+    ("XF86Numeric4", 0x10081204),
+    ("XF86Numeric5", 0x10081205),
+    ("XF86Numeric6", 0x10081206),
+    ("XF86Numeric7", 0x10081207),
+    ("XF86Numeric8", 0x10081208),
+    ("XF86Numeric9", 0x10081209),
+    ("XF86NumericA", 0x1008120c),
+    ("XF86NumericB", 0x1008120d),
+    ("XF86NumericC", 0x1008120e),
+    ("XF86NumericD", 0x1008120f),
+    ("XF86NumericPound", 0x1008120b),
+    ("XF86NumericStar", 0x1008120a),
+    ("XF86OfficeHome", 0x1008ff6a),
+    ("XF86OnScreenKeyboard", 0x10081278),
+    ("XF86Open", 0x1008ff6b),
+    ("XF86OpenURL", 0x1008ff38),
+    ("XF86Option", 0x1008ff6c),
+    ("XF86Paste", 0x1008ff6d),
+    ("XF86PauseRecord", 0x10081272),
+    ("XF86Phone", 0x1008ff6e),
+    ("XF86PickupPhone", 0x100811bd),
+    ("XF86Pictures", 0x1008ff91),
+    ("XF86PowerDown", 0x1008ff21),
+    ("XF86PowerOff", 0x1008ff2a),
+    ("XF86Presentation", 0x100811a9),
+    ("XF86Prev_VMode", 0x1008fe23),
+    ("XF86PrivacyScreenToggle", 0x10081279),
+    ("XF86Q", 0x1008ff70),
+    ("XF86RFKill", 0x1008ffb5),
+    ("XF86Red", 0x1008ffa3),
+    ("XF86Refresh", 0x1008ff29),
+    ("XF86Reload", 0x1008ff73),
+    ("XF86Reply", 0x1008ff72),
+    ("XF86RightDown", 0x10081267),
+    ("XF86RightUp", 0x10081266),
+    ("XF86RockerDown", 0x1008ff24),
+    ("XF86RockerEnter", 0x1008ff25),
+    ("XF86RockerUp", 0x1008ff23),
+    ("XF86RootMenu", 0x1008126a),
+    ("XF86RotateWindows", 0x1008ff74),
+    ("XF86RotationKB", 0x1008ff76),
+    ("XF86RotationLockToggle", 0x1008ffb7),
+    ("XF86RotationPB", 0x1008ff75),
+    ("XF86Save", 0x1008ff77),
+    ("XF86ScreenSaver", 0x1008ff2d),
+    ("XF86Screensaver", 0x10081245),
+    ("XF86ScrollClick", 0x1008ff7a),
+    ("XF86ScrollDown", 0x1008ff79),
+    ("XF86ScrollUp", 0x1008ff78),
+    ("XF86Search", 0x1008ff1b),
+    ("XF86Select", 0x1008ffa0),
+    ("XF86SelectiveScreenshot", 0x1008127a),
+    ("XF86Send", 0x1008ff7b),
+    ("XF86Shop", 0x1008ff36),
+    ("XF86Sleep", 0x1008ff2f),
+    ("XF86SlowReverse", 0x10081276),
+    ("XF86Spell", 0x1008ff7c),
+    ("XF86SpellCheck", 0x100811b0),
+    ("XF86SplitScreen", 0x1008ff7d),
+    ("XF86Standby", 0x1008ff10),
+    ("XF86Start", 0x1008ff1a),
+    ("XF86Stop", 0x1008ff28),
+    ("XF86StopRecord", 0x10081271),
+    ("XF86Subtitle", 0x1008ff9a),
+    ("XF86Support", 0x1008ff7e),
+    ("XF86Suspend", 0x1008ffa7),
+    ("XF86Switch_VT_1", 0x1008fe01),
+    ("XF86Switch_VT_10", 0x1008fe0a),
+    ("XF86Switch_VT_11", 0x1008fe0b),
+    ("XF86Switch_VT_12", 0x1008fe0c),
+    ("XF86Switch_VT_2", 0x1008fe02),
+    ("XF86Switch_VT_3", 0x1008fe03),
+    ("XF86Switch_VT_4", 0x1008fe04),
+    ("XF86Switch_VT_5", 0x1008fe05),
+    ("XF86Switch_VT_6", 0x1008fe06),
+    ("XF86Switch_VT_7", 0x1008fe07),
+    ("XF86Switch_VT_8", 0x1008fe08),
+    ("XF86Switch_VT_9", 0x1008fe09),
+    ("XF86TaskPane", 0x1008ff7f),
+    ("XF86Taskmanager", 0x10081241),
+    ("XF86Terminal", 0x1008ff80),
+    ("XF86Time", 0x1008ff9f),
+    ("XF86ToDoList", 0x1008ff1f),
+    ("XF86Tools", 0x1008ff81),
+    ("XF86TopMenu", 0x1008ffa2),
+    ("XF86TouchpadOff", 0x1008ffb1),
+    ("XF86TouchpadOn", 0x1008ffb0),
+    ("XF86TouchpadToggle", 0x1008ffa9),
+    ("XF86Travel", 0x1008ff82),
+    ("XF86UWB", 0x1008ff96),
+    ("XF86Ungrab", 0x1008fe20),
+    ("XF86Unmute", 0x10081274),
+    ("XF86User1KB", 0x1008ff85),
+    ("XF86User2KB", 0x1008ff86),
+    ("XF86UserPB", 0x1008ff84),
+    ("XF86VOD", 0x10081273),
+    ("XF86VendorHome", 0x1008ff34),
+    ("XF86Video", 0x1008ff87),
+    ("XF86VideoPhone", 0x100811a0),
+    ("XF86View", 0x1008ffa1),
+    ("XF86VoiceCommand", 0x10081246),
+    ("XF86Voicemail", 0x100811ac),
+    ("XF86WLAN", 0x1008ff95),
+    ("XF86WPSButton", 0x10081211),
+    ("XF86WWAN", 0x1008ffb4),
+    ("XF86WWW", 0x1008ff2e),
+    ("XF86WakeUp", 0x1008ff2b),
+    ("XF86WebCam", 0x1008ff8f),
+    ("XF86WheelButton", 0x1008ff88),
+    ("XF86Word", 0x1008ff89),
+    ("XF86Xfer", 0x1008ff8a),
+    ("XF86Yellow", 0x1008ffa5),
+    ("XF86ZoomIn", 0x1008ff8b),
+    ("XF86ZoomOut", 0x1008ff8c),
+    ("XF86ZoomReset", 0x100811a4),
+    ("XF86iTouch", 0x1008ff60),
+    ("Xabovedot", 0x1001e8a),
+    ("Y", 0x0059),
+    ("Yacute", 0x00dd),
+    ("Ybelowdot", 0x1001ef4),
+    ("Ycircumflex", 0x1000176),
+    ("Ydiaeresis", 0x13be),
+    ("Ygrave", 0x1001ef2),
+    ("Yhook", 0x1001ef6),
+    ("Ytilde", 0x1001ef8),
+    ("Z", 0x005a),
+    ("Zabovedot", 0x01af),
+    ("Zacute", 0x01ac),
+    ("Zcaron", 0x01ae),
+    ("Zen_Koho", 0xff3d),
+    ("Zenkaku", 0xff28),
+    ("Zenkaku_Hankaku", 0xff2a),
+    ("Zstroke", 0x10001b5),
+    ("a", 0x0061),
+    ("aacute", 0x00e1),
+    ("abelowdot", 0x1001ea1),
+    ("abovedot", 0x01ff),
+    ("abreve", 0x01e3),
+    ("abreveacute", 0x1001eaf),
+    ("abrevebelowdot", 0x1001eb7),
+    ("abrevegrave", 0x1001eb1),
+    ("abrevehook", 0x1001eb3),
+    ("abrevetilde", 0x1001eb5),
+    ("acircumflex", 0x00e2),
+    ("acircumflexacute", 0x1001ea5),
+    ("acircumflexbelowdot", 0x1001ead),
+    ("acircumflexgrave", 0x1001ea7),
+    ("acircumflexhook", 0x1001ea9),
+    ("acircumflextilde", 0x1001eab),
+    ("acute", 0x00b4),
+    ("adiaeresis", 0x00e4),
+    ("ae", 0x00e6),
+    ("agrave", 0x00e0),
+    ("ahook", 0x1001ea3),
+    ("amacron", 0x03e0),
+    ("ampersand", 0x0026),
+    ("aogonek", 0x01b1),
+    ("apostrophe", 0x0027),
+    ("approxeq", 0x1002248),
+    ("approximate", 0x08c8),
+    ("aring", 0x00e5),
+    ("asciicircum", 0x005e),
+    ("asciitilde", 0x007e),
+    ("asterisk", 0x002a),
+    ("at", 0x0040),
+    ("atilde", 0x00e3),
+    ("b", 0x0062),
+    ("babovedot", 0x1001e03),
+    ("backslash", 0x005c),
+    ("ballotcross", 0x0af4),
+    ("bar", 0x007c),
+    ("because", 0x1002235),
+    ("blank", 0x09df),
+    ("block", 0x100000fc),
+    ("botintegral", 0x08a5),
+    ("botleftparens", 0x08ac),
+    ("botleftsqbracket", 0x08a8),
+    ("botleftsummation", 0x08b2),
+    ("botrightparens", 0x08ae),
+    ("botrightsqbracket", 0x08aa),
+    ("botrightsummation", 0x08b6),
+    ("bott", 0x09f6),
+    ("botvertsummationconnector", 0x08b4),
+    ("braceleft", 0x007b),
+    ("braceright", 0x007d),
+    ("bracketleft", 0x005b),
+    ("bracketright", 0x005d),
+    ("braille_blank", 0x1002800),
+    ("braille_dot_1", 0xfff1),
+    ("braille_dot_10", 0xfffa),
+    ("braille_dot_2", 0xfff2),
+    ("braille_dot_3", 0xfff3),
+    ("braille_dot_4", 0xfff4),
+    ("braille_dot_5", 0xfff5),
+    ("braille_dot_6", 0xfff6),
+    ("braille_dot_7", 0xfff7),
+    ("braille_dot_8", 0xfff8),
+    ("braille_dot_9", 0xfff9),
+    ("braille_dots_1", 0x1002801),
+    ("braille_dots_12", 0x1002803),
+    ("braille_dots_123", 0x1002807),
+    ("braille_dots_1234", 0x100280f),
+    ("braille_dots_12345", 0x100281f),
+    ("braille_dots_123456", 0x100283f),
+    ("braille_dots_1234567", 0x100287f),
+    ("braille_dots_12345678", 0x10028ff),
+    ("braille_dots_1234568", 0x10028bf),
+    ("braille_dots_123457", 0x100285f),
+    ("braille_dots_1234578", 0x10028df),
+    ("braille_dots_123458", 0x100289f),
+    ("braille_dots_12346", 0x100282f),
+    ("braille_dots_123467", 0x100286f),
+    ("braille_dots_1234678", 0x10028ef),
+    ("braille_dots_123468", 0x10028af),
+    ("braille_dots_12347", 0x100284f),
+    ("braille_dots_123478", 0x10028cf),
+    ("braille_dots_12348", 0x100288f),
+    ("braille_dots_1235", 0x1002817),
+    ("braille_dots_12356", 0x1002837),
+    ("braille_dots_123567", 0x1002877),
+    ("braille_dots_1235678", 0x10028f7),
+    ("braille_dots_123568", 0x10028b7),
+    ("braille_dots_12357", 0x1002857),
+    ("braille_dots_123578", 0x10028d7),
+    ("braille_dots_12358", 0x1002897),
+    ("braille_dots_1236", 0x1002827),
+    ("braille_dots_12367", 0x1002867),
+    ("braille_dots_123678", 0x10028e7),
+    ("braille_dots_12368", 0x10028a7),
+    ("braille_dots_1237", 0x1002847),
+    ("braille_dots_12378", 0x10028c7),
+    ("braille_dots_1238", 0x1002887),
+    ("braille_dots_124", 0x100280b),
+    ("braille_dots_1245", 0x100281b),
+    ("braille_dots_12456", 0x100283b),
+    ("braille_dots_124567", 0x100287b),
+    ("braille_dots_1245678", 0x10028fb),
+    ("braille_dots_124568", 0x10028bb),
+    ("braille_dots_12457", 0x100285b),
+    ("braille_dots_124578", 0x10028db),
+    ("braille_dots_12458", 0x100289b),
+    ("braille_dots_1246", 0x100282b),
+    ("braille_dots_12467", 0x100286b),
+    ("braille_dots_124678", 0x10028eb),
+    ("braille_dots_12468", 0x10028ab),
+    ("braille_dots_1247", 0x100284b),
+    ("braille_dots_12478", 0x10028cb),
+    ("braille_dots_1248", 0x100288b),
+    ("braille_dots_125", 0x1002813),
+    ("braille_dots_1256", 0x1002833),
+    ("braille_dots_12567", 0x1002873),
+    ("braille_dots_125678", 0x10028f3),
+    ("braille_dots_12568", 0x10028b3),
+    ("braille_dots_1257", 0x1002853),
+    ("braille_dots_12578", 0x10028d3),
+    ("braille_dots_1258", 0x1002893),
+    ("braille_dots_126", 0x1002823),
+    ("braille_dots_1267", 0x1002863),
+    ("braille_dots_12678", 0x10028e3),
+    ("braille_dots_1268", 0x10028a3),
+    ("braille_dots_127", 0x1002843),
+    ("braille_dots_1278", 0x10028c3),
+    ("braille_dots_128", 0x1002883),
+    ("braille_dots_13", 0x1002805),
+    ("braille_dots_134", 0x100280d),
+    ("braille_dots_1345", 0x100281d),
+    ("braille_dots_13456", 0x100283d),
+    ("braille_dots_134567", 0x100287d),
+    ("braille_dots_1345678", 0x10028fd),
+    ("braille_dots_134568", 0x10028bd),
+    ("braille_dots_13457", 0x100285d),
+    ("braille_dots_134578", 0x10028dd),
+    ("braille_dots_13458", 0x100289d),
+    ("braille_dots_1346", 0x100282d),
+    ("braille_dots_13467", 0x100286d),
+    ("braille_dots_134678", 0x10028ed),
+    ("braille_dots_13468", 0x10028ad),
+    ("braille_dots_1347", 0x100284d),
+    ("braille_dots_13478", 0x10028cd),
+    ("braille_dots_1348", 0x100288d),
+    ("braille_dots_135", 0x1002815),
+    ("braille_dots_1356", 0x1002835),
+    ("braille_dots_13567", 0x1002875),
+    ("braille_dots_135678", 0x10028f5),
+    ("braille_dots_13568", 0x10028b5),
+    ("braille_dots_1357", 0x1002855),
+    ("braille_dots_13578", 0x10028d5),
+    ("braille_dots_1358", 0x1002895),
+    ("braille_dots_136", 0x1002825),
+    ("braille_dots_1367", 0x1002865),
+    ("braille_dots_13678", 0x10028e5),
+    ("braille_dots_1368", 0x10028a5),
+    ("braille_dots_137", 0x1002845),
+    ("braille_dots_1378", 0x10028c5),
+    ("braille_dots_138", 0x1002885),
+    ("braille_dots_14", 0x1002809),
+    ("braille_dots_145", 0x1002819),
+    ("braille_dots_1456", 0x1002839),
+    ("braille_dots_14567", 0x1002879),
+    ("braille_dots_145678", 0x10028f9),
+    ("braille_dots_14568", 0x10028b9),
+    ("braille_dots_1457", 0x1002859),
+    ("braille_dots_14578", 0x10028d9),
+    ("braille_dots_1458", 0x1002899),
+    ("braille_dots_146", 0x1002829),
+    ("braille_dots_1467", 0x1002869),
+    ("braille_dots_14678", 0x10028e9),
+    ("braille_dots_1468", 0x10028a9),
+    ("braille_dots_147", 0x1002849),
+    ("braille_dots_1478", 0x10028c9),
+    ("braille_dots_148", 0x1002889),
+    ("braille_dots_15", 0x1002811),
+    ("braille_dots_156", 0x1002831),
+    ("braille_dots_1567", 0x1002871),
+    ("braille_dots_15678", 0x10028f1),
+    ("braille_dots_1568", 0x10028b1),
+    ("braille_dots_157", 0x1002851),
+    ("braille_dots_1578", 0x10028d1),
+    ("braille_dots_158", 0x1002891),
+    ("braille_dots_16", 0x1002821),
+    ("braille_dots_167", 0x1002861),
+    ("braille_dots_1678", 0x10028e1),
+    ("braille_dots_168", 0x10028a1),
+    ("braille_dots_17", 0x1002841),
+    ("braille_dots_178", 0x10028c1),
+    ("braille_dots_18", 0x1002881),
+    ("braille_dots_2", 0x1002802),
+    ("braille_dots_23", 0x1002806),
+    ("braille_dots_234", 0x100280e),
+    ("braille_dots_2345", 0x100281e),
+    ("braille_dots_23456", 0x100283e),
+    ("braille_dots_234567", 0x100287e),
+    ("braille_dots_2345678", 0x10028fe),
+    ("braille_dots_234568", 0x10028be),
+    ("braille_dots_23457", 0x100285e),
+    ("braille_dots_234578", 0x10028de),
+    ("braille_dots_23458", 0x100289e),
+    ("braille_dots_2346", 0x100282e),
+    ("braille_dots_23467", 0x100286e),
+    ("braille_dots_234678", 0x10028ee),
+    ("braille_dots_23468", 0x10028ae),
+    ("braille_dots_2347", 0x100284e),
+    ("braille_dots_23478", 0x10028ce),
+    ("braille_dots_2348", 0x100288e),
+    ("braille_dots_235", 0x1002816),
+    ("braille_dots_2356", 0x1002836),
+    ("braille_dots_23567", 0x1002876),
+    ("braille_dots_235678", 0x10028f6),
+    ("braille_dots_23568", 0x10028b6),
+    ("braille_dots_2357", 0x1002856),
+    ("braille_dots_23578", 0x10028d6),
+    ("braille_dots_2358", 0x1002896),
+    ("braille_dots_236", 0x1002826),
+    ("braille_dots_2367", 0x1002866),
+    ("braille_dots_23678", 0x10028e6),
+    ("braille_dots_2368", 0x10028a6),
+    ("braille_dots_237", 0x1002846),
+    ("braille_dots_2378", 0x10028c6),
+    ("braille_dots_238", 0x1002886),
+    ("braille_dots_24", 0x100280a),
+    ("braille_dots_245", 0x100281a),
+    ("braille_dots_2456", 0x100283a),
+    ("braille_dots_24567", 0x100287a),
+    ("braille_dots_245678", 0x10028fa),
+    ("braille_dots_24568", 0x10028ba),
+    ("braille_dots_2457", 0x100285a),
+    ("braille_dots_24578", 0x10028da),
+    ("braille_dots_2458", 0x100289a),
+    ("braille_dots_246", 0x100282a),
+    ("braille_dots_2467", 0x100286a),
+    ("braille_dots_24678", 0x10028ea),
+    ("braille_dots_2468", 0x10028aa),
+    ("braille_dots_247", 0x100284a),
+    ("braille_dots_2478", 0x10028ca),
+    ("braille_dots_248", 0x100288a),
+    ("braille_dots_25", 0x1002812),
+    ("braille_dots_256", 0x1002832),
+    ("braille_dots_2567", 0x1002872),
+    ("braille_dots_25678", 0x10028f2),
+    ("braille_dots_2568", 0x10028b2),
+    ("braille_dots_257", 0x1002852),
+    ("braille_dots_2578", 0x10028d2),
+    ("braille_dots_258", 0x1002892),
+    ("braille_dots_26", 0x1002822),
+    ("braille_dots_267", 0x1002862),
+    ("braille_dots_2678", 0x10028e2),
+    ("braille_dots_268", 0x10028a2),
+    ("braille_dots_27", 0x1002842),
+    ("braille_dots_278", 0x10028c2),
+    ("braille_dots_28", 0x1002882),
+    ("braille_dots_3", 0x1002804),
+    ("braille_dots_34", 0x100280c),
+    ("braille_dots_345", 0x100281c),
+    ("braille_dots_3456", 0x100283c),
+    ("braille_dots_34567", 0x100287c),
+    ("braille_dots_345678", 0x10028fc),
+    ("braille_dots_34568", 0x10028bc),
+    ("braille_dots_3457", 0x100285c),
+    ("braille_dots_34578", 0x10028dc),
+    ("braille_dots_3458", 0x100289c),
+    ("braille_dots_346", 0x100282c),
+    ("braille_dots_3467", 0x100286c),
+    ("braille_dots_34678", 0x10028ec),
+    ("braille_dots_3468", 0x10028ac),
+    ("braille_dots_347", 0x100284c),
+    ("braille_dots_3478", 0x10028cc),
+    ("braille_dots_348", 0x100288c),
+    ("braille_dots_35", 0x1002814),
+    ("braille_dots_356", 0x1002834),
+    ("braille_dots_3567", 0x1002874),
+    ("braille_dots_35678", 0x10028f4),
+    ("braille_dots_3568", 0x10028b4),
+    ("braille_dots_357", 0x1002854),
+    ("braille_dots_3578", 0x10028d4),
+    ("braille_dots_358", 0x1002894),
+    ("braille_dots_36", 0x1002824),
+    ("braille_dots_367", 0x1002864),
+    ("braille_dots_3678", 0x10028e4),
+    ("braille_dots_368", 0x10028a4),
+    ("braille_dots_37", 0x1002844),
+    ("braille_dots_378", 0x10028c4),
+    ("braille_dots_38", 0x1002884),
+    ("braille_dots_4", 0x1002808),
+    ("braille_dots_45", 0x1002818),
+    ("braille_dots_456", 0x1002838),
+    ("braille_dots_4567", 0x1002878),
+    ("braille_dots_45678", 0x10028f8),
+    ("braille_dots_4568", 0x10028b8),
+    ("braille_dots_457", 0x1002858),
+    ("braille_dots_4578", 0x10028d8),
+    ("braille_dots_458", 0x1002898),
+    ("braille_dots_46", 0x1002828),
+    ("braille_dots_467", 0x1002868),
+    ("braille_dots_4678", 0x10028e8),
+    ("braille_dots_468", 0x10028a8),
+    ("braille_dots_47", 0x1002848),
+    ("braille_dots_478", 0x10028c8),
+    ("braille_dots_48", 0x1002888),
+    ("braille_dots_5", 0x1002810),
+    ("braille_dots_56", 0x1002830),
+    ("braille_dots_567", 0x1002870),
+    ("braille_dots_5678", 0x10028f0),
+    ("braille_dots_568", 0x10028b0),
+    ("braille_dots_57", 0x1002850),
+    ("braille_dots_578", 0x10028d0),
+    ("braille_dots_58", 0x1002890),
+    ("braille_dots_6", 0x1002820),
+    ("braille_dots_67", 0x1002860),
+    ("braille_dots_678", 0x10028e0),
+    ("braille_dots_68", 0x10028a0),
+    ("braille_dots_7", 0x1002840),
+    ("braille_dots_78", 0x10028c0),
+    ("braille_dots_8", 0x1002880),
+    ("breve", 0x01a2),
+    ("brokenbar", 0x00a6),
+    ("c", 0x0063),
+    ("c_h", 0xfea3),
+    ("cabovedot", 0x02e5),
+    ("cacute", 0x01e6),
+    ("careof", 0x0ab8),
+    ("caret", 0x0afc),
+    ("caron", 0x01b7),
+    ("ccaron", 0x01e8),
+    ("ccedilla", 0x00e7),
+    ("ccircumflex", 0x02e6),
+    ("cedilla", 0x00b8),
+    ("cent", 0x00a2),
+    ("ch", 0xfea0),
+    ("checkerboard", 0x09e1),
+    ("checkmark", 0x0af3),
+    ("circle", 0x0bcf),
+    ("club", 0x0aec),
+    ("colon", 0x003a),
+    ("combining_acute", 0x1000301),
+    ("combining_belowdot", 0x1000323),
+    ("combining_grave", 0x1000300),
+    ("combining_hook", 0x1000309),
+    ("combining_tilde", 0x1000303),
+    ("comma", 0x002c),
+    ("containsas", 0x100220b),
+    ("copyright", 0x00a9),
+    ("cr", 0x09e4),
+    ("crossinglines", 0x09ee),
+    ("cuberoot", 0x100221b),
+    ("currency", 0x00a4),
+    ("cursor", 0x0aff),
+    ("d", 0x0064),
+    ("dabovedot", 0x1001e0b),
+    ("dagger", 0x0af1),
+    ("dcaron", 0x01ef),
+    ("dead_A", 0xfe81),
+    ("dead_E", 0xfe83),
+    ("dead_I", 0xfe85),
+    ("dead_O", 0xfe87),
+    ("dead_U", 0xfe89),
+    ("dead_a", 0xfe80),
+    ("dead_abovecomma", 0xfe64),
+    ("dead_abovedot", 0xfe56),
+    ("dead_abovereversedcomma", 0xfe65),
+    ("dead_abovering", 0xfe58),
+    ("dead_aboveverticalline", 0xfe91),
+    ("dead_acute", 0xfe51),
+    ("dead_belowbreve", 0xfe6b),
+    ("dead_belowcircumflex", 0xfe69),
+    ("dead_belowcomma", 0xfe6e),
+    ("dead_belowdiaeresis", 0xfe6c),
+    ("dead_belowdot", 0xfe60),
+    ("dead_belowmacron", 0xfe68),
+    ("dead_belowring", 0xfe67),
+    ("dead_belowtilde", 0xfe6a),
+    ("dead_belowverticalline", 0xfe92),
+    ("dead_breve", 0xfe55),
+    ("dead_capital_schwa", 0xfe8b),
+    ("dead_caron", 0xfe5a),
+    ("dead_cedilla", 0xfe5b),
+    ("dead_circumflex", 0xfe52),
+    ("dead_currency", 0xfe6f),
+    ("dead_dasia", 0xfe65),
+    ("dead_diaeresis", 0xfe57),
+    ("dead_doubleacute", 0xfe59),
+    ("dead_doublegrave", 0xfe66),
+    ("dead_e", 0xfe82),
+    ("dead_grave", 0xfe50),
+    ("dead_greek", 0xfe8c),
+    ("dead_hook", 0xfe61),
+    ("dead_horn", 0xfe62),
+    ("dead_i", 0xfe84),
+    ("dead_invertedbreve", 0xfe6d),
+    ("dead_iota", 0xfe5d),
+    ("dead_longsolidusoverlay", 0xfe93),
+    ("dead_lowline", 0xfe90),
+    ("dead_macron", 0xfe54),
+    ("dead_o", 0xfe86),
+    ("dead_ogonek", 0xfe5c),
+    ("dead_perispomeni", 0xfe53),
+    ("dead_psili", 0xfe64),
+    ("dead_semivoiced_sound", 0xfe5f),
+    ("dead_small_schwa", 0xfe8a),
+    ("dead_stroke", 0xfe63),
+    ("dead_tilde", 0xfe53),
+    ("dead_u", 0xfe88),
+    ("dead_voiced_sound", 0xfe5e),
+    ("decimalpoint", 0x0abd),
+    ("degree", 0x00b0),
+    ("diaeresis", 0x00a8),
+    ("diamond", 0x0aed),
+    ("digitspace", 0x0aa5),
+    ("dintegral", 0x100222c),
+    ("division", 0x00f7),
+    ("dollar", 0x0024),
+    ("doubbaselinedot", 0x0aaf),
+    ("doubleacute", 0x01bd),
+    ("doubledagger", 0x0af2),
+    ("doublelowquotemark", 0x0afe),
+    ("downarrow", 0x08fe),
+    ("downcaret", 0x0ba8),
+    ("downshoe", 0x0bd6),
+    ("downstile", 0x0bc4),
+    ("downtack", 0x0bc2),
+    ("dstroke", 0x01f0),
+    ("e", 0x0065),
+    ("eabovedot", 0x03ec),
+    ("eacute", 0x00e9),
+    ("ebelowdot", 0x1001eb9),
+    ("ecaron", 0x01ec),
+    ("ecircumflex", 0x00ea),
+    ("ecircumflexacute", 0x1001ebf),
+    ("ecircumflexbelowdot", 0x1001ec7),
+    ("ecircumflexgrave", 0x1001ec1),
+    ("ecircumflexhook", 0x1001ec3),
+    ("ecircumflextilde", 0x1001ec5),
+    ("ediaeresis", 0x00eb),
+    ("egrave", 0x00e8),
+    ("ehook", 0x1001ebb),
+    ("eightsubscript", 0x1002088),
+    ("eightsuperior", 0x1002078),
+    ("elementof", 0x1002208),
+    ("ellipsis", 0x0aae),
+    ("em3space", 0x0aa3),
+    ("em4space", 0x0aa4),
+    ("emacron", 0x03ba),
+    ("emdash", 0x0aa9),
+    ("emfilledcircle", 0x0ade),
+    ("emfilledrect", 0x0adf),
+    ("emopencircle", 0x0ace),
+    ("emopenrectangle", 0x0acf),
+    ("emptyset", 0x1002205),
+    ("emspace", 0x0aa1),
+    ("endash", 0x0aaa),
+    ("enfilledcircbullet", 0x0ae6),
+    ("enfilledsqbullet", 0x0ae7),
+    ("eng", 0x03bf),
+    ("enopencircbullet", 0x0ae0),
+    ("enopensquarebullet", 0x0ae1),
+    ("enspace", 0x0aa2),
+    ("eogonek", 0x01ea),
+    ("equal", 0x003d),
+    ("eth", 0x00f0),
+    ("etilde", 0x1001ebd),
+    ("exclam", 0x0021),
+    ("exclamdown", 0x00a1),
+    ("ezh", 0x1000292),
+    ("f", 0x0066),
+    ("fabovedot", 0x1001e1f),
+    ("femalesymbol", 0x0af8),
+    ("ff", 0x09e3),
+    ("figdash", 0x0abb),
+    ("filledlefttribullet", 0x0adc),
+    ("filledrectbullet", 0x0adb),
+    ("filledrighttribullet", 0x0add),
+    ("filledtribulletdown", 0x0ae9),
+    ("filledtribulletup", 0x0ae8),
+    ("fiveeighths", 0x0ac5),
+    ("fivesixths", 0x0ab7),
+    ("fivesubscript", 0x1002085),
+    ("fivesuperior", 0x1002075),
+    ("fourfifths", 0x0ab5),
+    ("foursubscript", 0x1002084),
+    ("foursuperior", 0x1002074),
+    ("fourthroot", 0x100221c),
+    ("function", 0x08f6),
+    ("g", 0x0067),
+    ("gabovedot", 0x02f5),
+    ("gbreve", 0x02bb),
+    ("gcaron", 0x10001e7),
+    ("gcedilla", 0x03bb),
+    ("gcircumflex", 0x02f8),
+    ("grave", 0x0060),
+    ("greater", 0x003e),
+    ("greaterthanequal", 0x08be),
+    ("guilder", 0x100000be),
+    ("guillemotleft", 0x00ab),
+    ("guillemotright", 0x00bb),
+    ("h", 0x0068),
+    ("hairspace", 0x0aa8),
+    ("hcircumflex", 0x02b6),
+    ("heart", 0x0aee),
+    ("hebrew_aleph", 0x0ce0),
+    ("hebrew_ayin", 0x0cf2),
+    ("hebrew_bet", 0x0ce1),
+    ("hebrew_beth", 0x0ce1),
+    ("hebrew_chet", 0x0ce7),
+    ("hebrew_dalet", 0x0ce3),
+    ("hebrew_daleth", 0x0ce3),
+    ("hebrew_doublelowline", 0x0cdf),
+    ("hebrew_finalkaph", 0x0cea),
+    ("hebrew_finalmem", 0x0ced),
+    ("hebrew_finalnun", 0x0cef),
+    ("hebrew_finalpe", 0x0cf3),
+    ("hebrew_finalzade", 0x0cf5),
+    ("hebrew_finalzadi", 0x0cf5),
+    ("hebrew_gimel", 0x0ce2),
+    ("hebrew_gimmel", 0x0ce2),
+    ("hebrew_he", 0x0ce4),
+    ("hebrew_het", 0x0ce7),
+    ("hebrew_kaph", 0x0ceb),
+    ("hebrew_kuf", 0x0cf7),
+    ("hebrew_lamed", 0x0cec),
+    ("hebrew_mem", 0x0cee),
+    ("hebrew_nun", 0x0cf0),
+    ("hebrew_pe", 0x0cf4),
+    ("hebrew_qoph", 0x0cf7),
+    ("hebrew_resh", 0x0cf8),
+    ("hebrew_samech", 0x0cf1),
+    ("hebrew_samekh", 0x0cf1),
+    ("hebrew_shin", 0x0cf9),
+    ("hebrew_taf", 0x0cfa),
+    ("hebrew_taw", 0x0cfa),
+    ("hebrew_tet", 0x0ce8),
+    ("hebrew_teth", 0x0ce8),
+    ("hebrew_waw", 0x0ce5),
+    ("hebrew_yod", 0x0ce9),
+    ("hebrew_zade", 0x0cf6),
+    ("hebrew_zadi", 0x0cf6),
+    ("hebrew_zain", 0x0ce6),
+    ("hebrew_zayin", 0x0ce6),
+    ("hexagram", 0x0ada),
+    ("horizconnector", 0x08a3),
+    ("horizlinescan1", 0x09ef),
+    ("horizlinescan3", 0x09f0),
+    ("horizlinescan5", 0x09f1),
+    ("horizlinescan7", 0x09f2),
+    ("horizlinescan9", 0x09f3),
+    ("hpBackTab", 0x1000ff74),
+    ("hpClearLine", 0x1000ff6f),
+    ("hpDeleteChar", 0x1000ff73),
+    ("hpDeleteLine", 0x1000ff71),
+    ("hpIO", 0x100000ee),
+    ("hpInsertChar", 0x1000ff72),
+    ("hpInsertLine", 0x1000ff70),
+    ("hpKP_BackTab", 0x1000ff75),
+    ("hpModelock1", 0x1000ff48),
+    ("hpModelock2", 0x1000ff49),
+    ("hpReset", 0x1000ff6c),
+    ("hpSystem", 0x1000ff6d),
+    ("hpUser", 0x1000ff6e),
+    ("hpYdiaeresis", 0x100000ee),
+    ("hpblock", 0x100000fc),
+    ("hpguilder", 0x100000be),
+    ("hplira", 0x100000af),
+    ("hplongminus", 0x100000f6),
+    ("hpmute_acute", 0x100000a8),
+    ("hpmute_asciicircum", 0x100000aa),
+    ("hpmute_asciitilde", 0x100000ac),
+    ("hpmute_diaeresis", 0x100000ab),
+    ("hpmute_grave", 0x100000a9),
+    ("hstroke", 0x02b1),
+    ("ht", 0x09e2),
+    ("hyphen", 0x00ad),
+    ("i", 0x0069),
+    ("iacute", 0x00ed),
+    ("ibelowdot", 0x1001ecb),
+    ("ibreve", 0x100012d),
+    ("icircumflex", 0x00ee),
+    ("identical", 0x08cf),
+    ("idiaeresis", 0x00ef),
+    ("idotless", 0x02b9),
+    ("ifonlyif", 0x08cd),
+    ("igrave", 0x00ec),
+    ("ihook", 0x1001ec9),
+    ("imacron", 0x03ef),
+    ("implies", 0x08ce),
+    ("includedin", 0x08da),
+    ("includes", 0x08db),
+    ("infinity", 0x08c2),
+    ("integral", 0x08bf),
+    ("intersection", 0x08dc),
+    ("iogonek", 0x03e7),
+    ("itilde", 0x03b5),
+    ("j", 0x006a),
+    ("jcircumflex", 0x02bc),
+    ("jot", 0x0bca),
+    ("k", 0x006b),
+    ("kana_A", 0x04b1),
+    ("kana_CHI", 0x04c1),
+    ("kana_E", 0x04b4),
+    ("kana_FU", 0x04cc),
+    ("kana_HA", 0x04ca),
+    ("kana_HE", 0x04cd),
+    ("kana_HI", 0x04cb),
+    ("kana_HO", 0x04ce),
+    ("kana_HU", 0x04cc),
+    ("kana_I", 0x04b2),
+    ("kana_KA", 0x04b6),
+    ("kana_KE", 0x04b9),
+    ("kana_KI", 0x04b7),
+    ("kana_KO", 0x04ba),
+    ("kana_KU", 0x04b8),
+    ("kana_MA", 0x04cf),
+    ("kana_ME", 0x04d2),
+    ("kana_MI", 0x04d0),
+    ("kana_MO", 0x04d3),
+    ("kana_MU", 0x04d1),
+    ("kana_N", 0x04dd),
+    ("kana_NA", 0x04c5),
+    ("kana_NE", 0x04c8),
+    ("kana_NI", 0x04c6),
+    ("kana_NO", 0x04c9),
+    ("kana_NU", 0x04c7),
+    ("kana_O", 0x04b5),
+    ("kana_RA", 0x04d7),
+    ("kana_RE", 0x04da),
+    ("kana_RI", 0x04d8),
+    ("kana_RO", 0x04db),
+    ("kana_RU", 0x04d9),
+    ("kana_SA", 0x04bb),
+    ("kana_SE", 0x04be),
+    ("kana_SHI", 0x04bc),
+    ("kana_SO", 0x04bf),
+    ("kana_SU", 0x04bd),
+    ("kana_TA", 0x04c0),
+    ("kana_TE", 0x04c3),
+    ("kana_TI", 0x04c1),
+    ("kana_TO", 0x04c4),
+    ("kana_TSU", 0x04c2),
+    ("kana_TU", 0x04c2),
+    ("kana_U", 0x04b3),
+    ("kana_WA", 0x04dc),
+    ("kana_WO", 0x04a6),
+    ("kana_YA", 0x04d4),
+    ("kana_YO", 0x04d6),
+    ("kana_YU", 0x04d5),
+    ("kana_a", 0x04a7),
+    ("kana_closingbracket", 0x04a3),
+    ("kana_comma", 0x04a4),
+    ("kana_conjunctive", 0x04a5),
+    ("kana_e", 0x04aa),
+    ("kana_fullstop", 0x04a1),
+    ("kana_i", 0x04a8),
+    ("kana_middledot", 0x04a5),
+    ("kana_o", 0x04ab),
+    ("kana_openingbracket", 0x04a2),
+    ("kana_switch", 0xff7e),
+    ("kana_tsu", 0x04af),
+    ("kana_tu", 0x04af),
+    ("kana_u", 0x04a9),
+    ("kana_ya", 0x04ac),
+    ("kana_yo", 0x04ae),
+    ("kana_yu", 0x04ad),
+    ("kappa", 0x03a2),
+    ("kcedilla", 0x03f3),
+    ("kra", 0x03a2),
+    ("l", 0x006c),
+    ("lacute", 0x01e5),
+    ("latincross", 0x0ad9),
+    ("lbelowdot", 0x1001e37),
+    ("lcaron", 0x01b5),
+    ("lcedilla", 0x03b6),
+    ("leftanglebracket", 0x0abc),
+    ("leftarrow", 0x08fb),
+    ("leftcaret", 0x0ba3),
+    ("leftdoublequotemark", 0x0ad2),
+    ("leftmiddlecurlybrace", 0x08af),
+    ("leftopentriangle", 0x0acc),
+    ("leftpointer", 0x0aea),
+    ("leftradical", 0x08a1),
+    ("leftshoe", 0x0bda),
+    ("leftsinglequotemark", 0x0ad0),
+    ("leftt", 0x09f4),
+    ("lefttack", 0x0bdc),
+    ("less", 0x003c),
+    ("lessthanequal", 0x08bc),
+    ("lf", 0x09e5),
+    ("lira", 0x100000af),
+    ("logicaland", 0x08de),
+    ("logicalor", 0x08df),
+    ("longminus", 0x100000f6),
+    ("lowleftcorner", 0x09ed),
+    ("lowrightcorner", 0x09ea),
+    ("lstroke", 0x01b3),
+    ("m", 0x006d),
+    ("mabovedot", 0x1001e41),
+    ("macron", 0x00af),
+    ("malesymbol", 0x0af7),
+    ("maltesecross", 0x0af0),
+    ("marker", 0x0abf),
+    ("masculine", 0x00ba),
+    ("minus", 0x002d),
+    ("minutes", 0x0ad6),
+    ("mu", 0x00b5),
+    ("multiply", 0x00d7),
+    ("musicalflat", 0x0af6),
+    ("musicalsharp", 0x0af5),
+    ("mute_acute", 0x100000a8),
+    ("mute_asciicircum", 0x100000aa),
+    ("mute_asciitilde", 0x100000ac),
+    ("mute_diaeresis", 0x100000ab),
+    ("mute_grave", 0x100000a9),
+    ("n", 0x006e),
+    ("nabla", 0x08c5),
+    ("nacute", 0x01f1),
+    ("ncaron", 0x01f2),
+    ("ncedilla", 0x03f1),
+    ("ninesubscript", 0x1002089),
+    ("ninesuperior", 0x1002079),
+    ("nl", 0x09e8),
+    ("nobreakspace", 0x00a0),
+    ("notapproxeq", 0x1002247),
+    ("notelementof", 0x1002209),
+    ("notequal", 0x08bd),
+    ("notidentical", 0x1002262),
+    ("notsign", 0x00ac),
+    ("ntilde", 0x00f1),
+    ("numbersign", 0x0023),
+    ("numerosign", 0x06b0),
+    ("o", 0x006f),
+    ("oacute", 0x00f3),
+    ("obarred", 0x1000275),
+    ("obelowdot", 0x1001ecd),
+    ("ocaron", 0x10001d2),
+    ("ocircumflex", 0x00f4),
+    ("ocircumflexacute", 0x1001ed1),
+    ("ocircumflexbelowdot", 0x1001ed9),
+    ("ocircumflexgrave", 0x1001ed3),
+    ("ocircumflexhook", 0x1001ed5),
+    ("ocircumflextilde", 0x1001ed7),
+    ("odiaeresis", 0x00f6),
+    ("odoubleacute", 0x01f5),
+    ("oe", 0x13bd),
+    ("ogonek", 0x01b2),
+    ("ograve", 0x00f2),
+    ("ohook", 0x1001ecf),
+    ("ohorn", 0x10001a1),
+    ("ohornacute", 0x1001edb),
+    ("ohornbelowdot", 0x1001ee3),
+    ("ohorngrave", 0x1001edd),
+    ("ohornhook", 0x1001edf),
+    ("ohorntilde", 0x1001ee1),
+    ("omacron", 0x03f2),
+    ("oneeighth", 0x0ac3),
+    ("onefifth", 0x0ab2),
+    ("onehalf", 0x00bd),
+    ("onequarter", 0x00bc),
+    ("onesixth", 0x0ab6),
+    ("onesubscript", 0x1002081),
+    ("onesuperior", 0x00b9),
+    ("onethird", 0x0ab0),
+    ("ooblique", 0x00f8),
+    ("openrectbullet", 0x0ae2),
+    ("openstar", 0x0ae5),
+    ("opentribulletdown", 0x0ae4),
+    ("opentribulletup", 0x0ae3),
+    ("ordfeminine", 0x00aa),
+    ("osfActivate", 0x1004ff44),
+    ("osfAddMode", 0x1004ff31),
+    ("osfBackSpace", 0x1004ff08),
+    ("osfBackTab", 0x1004ff07),
+    ("osfBeginData", 0x1004ff5a),
+    ("osfBeginLine", 0x1004ff58),
+    ("osfCancel", 0x1004ff69),
+    ("osfClear", 0x1004ff0b),
+    ("osfCopy", 0x1004ff02),
+    ("osfCut", 0x1004ff03),
+    ("osfDelete", 0x1004ffff),
+    ("osfDeselectAll", 0x1004ff72),
+    ("osfDown", 0x1004ff54),
+    ("osfEndData", 0x1004ff59),
+    ("osfEndLine", 0x1004ff57),
+    ("osfEscape", 0x1004ff1b),
+    ("osfExtend", 0x1004ff74),
+    ("osfHelp", 0x1004ff6a),
+    ("osfInsert", 0x1004ff63),
+    ("osfLeft", 0x1004ff51),
+    ("osfMenu", 0x1004ff67),
+    ("osfMenuBar", 0x1004ff45),
+    ("osfNextField", 0x1004ff5e),
+    ("osfNextMenu", 0x1004ff5c),
+    ("osfPageDown", 0x1004ff42),
+    ("osfPageLeft", 0x1004ff40),
+    ("osfPageRight", 0x1004ff43),
+    ("osfPageUp", 0x1004ff41),
+    ("osfPaste", 0x1004ff04),
+    ("osfPrevField", 0x1004ff5d),
+    ("osfPrevMenu", 0x1004ff5b),
+    ("osfPrimaryPaste", 0x1004ff32),
+    ("osfQuickPaste", 0x1004ff33),
+    ("osfReselect", 0x1004ff73),
+    ("osfRestore", 0x1004ff78),
+    ("osfRight", 0x1004ff53),
+    ("osfSelect", 0x1004ff60),
+    ("osfSelectAll", 0x1004ff71),
+    ("osfUndo", 0x1004ff65),
+    ("osfUp", 0x1004ff52),
+    ("oslash", 0x00f8),
+    ("otilde", 0x00f5),
+    ("overbar", 0x0bc0),
+    ("overline", 0x047e),
+    ("p", 0x0070),
+    ("pabovedot", 0x1001e57),
+    ("paragraph", 0x00b6),
+    ("parenleft", 0x0028),
+    ("parenright", 0x0029),
+    ("partdifferential", 0x1002202),
+    ("partialderivative", 0x08ef),
+    ("percent", 0x0025),
+    ("period", 0x002e),
+    ("periodcentered", 0x00b7),
+    ("permille", 0x0ad5),
+    ("phonographcopyright", 0x0afb),
+    ("plus", 0x002b),
+    ("plusminus", 0x00b1),
+    ("prescription", 0x0ad4),
+    ("prolongedsound", 0x04b0),
+    ("punctspace", 0x0aa6),
+    ("q", 0x0071),
+    ("quad", 0x0bcc),
+    ("question", 0x003f),
+    ("questiondown", 0x00bf),
+    ("quotedbl", 0x0022),
+    ("quoteleft", 0x0060),
+    ("quoteright", 0x0027),
+    ("r", 0x0072),
+    ("racute", 0x01e0),
+    ("radical", 0x08d6),
+    ("rcaron", 0x01f8),
+    ("rcedilla", 0x03b3),
+    ("registered", 0x00ae),
+    ("rightanglebracket", 0x0abe),
+    ("rightarrow", 0x08fd),
+    ("rightcaret", 0x0ba6),
+    ("rightdoublequotemark", 0x0ad3),
+    ("rightmiddlecurlybrace", 0x08b0),
+    ("rightmiddlesummation", 0x08b7),
+    ("rightopentriangle", 0x0acd),
+    ("rightpointer", 0x0aeb),
+    ("rightshoe", 0x0bd8),
+    ("rightsinglequotemark", 0x0ad1),
+    ("rightt", 0x09f5),
+    ("righttack", 0x0bfc),
+    ("s", 0x0073),
+    ("sabovedot", 0x1001e61),
+    ("sacute", 0x01b6),
+    ("scaron", 0x01b9),
+    ("scedilla", 0x01ba),
+    ("schwa", 0x1000259),
+    ("scircumflex", 0x02fe),
+    ("script_switch", 0xff7e),
+    ("seconds", 0x0ad7),
+    ("section", 0x00a7),
+    ("semicolon", 0x003b),
+    ("semivoicedsound", 0x04df),
+    ("seveneighths", 0x0ac6),
+    ("sevensubscript", 0x1002087),
+    ("sevensuperior", 0x1002077),
+    ("signaturemark", 0x0aca),
+    ("signifblank", 0x0aac),
+    ("similarequal", 0x08c9),
+    ("singlelowquotemark", 0x0afd),
+    ("sixsubscript", 0x1002086),
+    ("sixsuperior", 0x1002076),
+    ("slash", 0x002f),
+    ("soliddiamond", 0x09e0),
+    ("space", 0x0020),
+    ("squareroot", 0x100221a),
+    ("ssharp", 0x00df),
+    ("sterling", 0x00a3),
+    ("stricteq", 0x1002263),
+    ("t", 0x0074),
+    ("tabovedot", 0x1001e6b),
+    ("tcaron", 0x01bb),
+    ("tcedilla", 0x01fe),
+    ("telephone", 0x0af9),
+    ("telephonerecorder", 0x0afa),
+    ("therefore", 0x08c0),
+    ("thinspace", 0x0aa7),
+    ("thorn", 0x00fe),
+    ("threeeighths", 0x0ac4),
+    ("threefifths", 0x0ab4),
+    ("threequarters", 0x00be),
+    ("threesubscript", 0x1002083),
+    ("threesuperior", 0x00b3),
+    ("tintegral", 0x100222d),
+    ("topintegral", 0x08a4),
+    ("topleftparens", 0x08ab),
+    ("topleftradical", 0x08a2),
+    ("topleftsqbracket", 0x08a7),
+    ("topleftsummation", 0x08b1),
+    ("toprightparens", 0x08ad),
+    ("toprightsqbracket", 0x08a9),
+    ("toprightsummation", 0x08b5),
+    ("topt", 0x09f7),
+    ("topvertsummationconnector", 0x08b3),
+    ("trademark", 0x0ac9),
+    ("trademarkincircle", 0x0acb),
+    ("tslash", 0x03bc),
+    ("twofifths", 0x0ab3),
+    ("twosubscript", 0x1002082),
+    ("twosuperior", 0x00b2),
+    ("twothirds", 0x0ab1),
+    ("u", 0x0075),
+    ("uacute", 0x00fa),
+    ("ubelowdot", 0x1001ee5),
+    ("ubreve", 0x02fd),
+    ("ucircumflex", 0x00fb),
+    ("udiaeresis", 0x00fc),
+    ("udoubleacute", 0x01fb),
+    ("ugrave", 0x00f9),
+    ("uhook", 0x1001ee7),
+    ("uhorn", 0x10001b0),
+    ("uhornacute", 0x1001ee9),
+    ("uhornbelowdot", 0x1001ef1),
+    ("uhorngrave", 0x1001eeb),
+    ("uhornhook", 0x1001eed),
+    ("uhorntilde", 0x1001eef),
+    ("umacron", 0x03fe),
+    ("underbar", 0x0bc6),
+    ("underscore", 0x005f),
+    ("union", 0x08dd),
+    ("uogonek", 0x03f9),
+    ("uparrow", 0x08fc),
+    ("upcaret", 0x0ba9),
+    ("upleftcorner", 0x09ec),
+    ("uprightcorner", 0x09eb),
+    ("upshoe", 0x0bc3),
+    ("upstile", 0x0bd3),
+    ("uptack", 0x0bce),
+    ("uring", 0x01f9),
+    ("utilde", 0x03fd),
+    ("v", 0x0076),
+    ("variation", 0x08c1),
+    ("vertbar", 0x09f8),
+    ("vertconnector", 0x08a6),
+    ("voicedsound", 0x04de),
+    ("vt", 0x09e9),
+    ("w", 0x0077),
+    ("wacute", 0x1001e83),
+    ("wcircumflex", 0x1000175),
+    ("wdiaeresis", 0x1001e85),
+    ("wgrave", 0x1001e81),
+    ("x", 0x0078),
+    ("xabovedot", 0x1001e8b),
+    ("y", 0x0079),
+    ("yacute", 0x00fd),
+    ("ybelowdot", 0x1001ef5),
+    ("ycircumflex", 0x1000177),
+    ("ydiaeresis", 0x00ff),
+    ("yen", 0x00a5),
+    ("ygrave", 0x1001ef3),
+    ("yhook", 0x1001ef7),
+    ("ytilde", 0x1001ef9),
+    ("z", 0x007a),
+    ("zabovedot", 0x01bf),
+    ("zacute", 0x01bc),
+    ("zcaron", 0x01be),
+    ("zerosubscript", 0x1002080),
+    ("zerosuperior", 0x1002070),
+    ("zstroke", 0x10001b6),
+];
+
+/// Characters of the keysyms that are neither Latin 1 nor unicode keysyms, sorted by
+/// keysym
+const KEYSYM_CHARS: &[(u32, char)] = &[
+    (0x01a1, '\u{0104}'),
+    (0x01a2, '\u{02d8}'),
+    (0x01a3, '\u{0141}'),
+    (0x01a5, '\u{013d}'),
+    (0x01a6, '\u{015a}'),
+    (0x01a9, '\u{0160}'),
+    (0x01aa, '\u{015e}'),
+    (0x01ab, '\u{0164}'),
+    (0x01ac, '\u{0179}'),
+    (0x01ae, '\u{017d}'),
+    (0x01af, '\u{017b}'),
+    (0x01b1, '\u{0105}'),
+    (0x01b2, '\u{02db}'),
+    (0x01b3, '\u{0142}'),
+    (0x01b5, '\u{013e}'),
+    (0x01b6, '\u{015b}'),
+    (0x01b7, '\u{02c7}'),
+    (0x01b9, '\u{0161}'),
+    (0x01ba, '\u{015f}'),
+    (0x01bb, '\u{0165}'),
+    (0x01bc, '\u{017a}'),
+    (0x01bd, '\u{02dd}'),
+    (0x01be, '\u{017e}'),
+    (0x01bf, '\u{017c}'),
+    (0x01c0, '\u{0154}'),
+    (0x01c3, '\u{0102}'),
+    (0x01c5, '\u{0139}'),
+    (0x01c6, '\u{0106}'),
+    (0x01c8, '\u{010c}'),
+    (0x01ca, '\u{0118}'),
+    (0x01cc, '\u{011a}'),
+    (0x01cf, '\u{010e}'),
+    (0x01d0, '\u{0110}'),
+    (0x01d1, '\u{0143}'),
+    (0x01d2, '\u{0147}'),
+    (0x01d5, '\u{0150}'),
+    (0x01d8, '\u{0158}'),
+    (0x01d9, '\u{016e}'),
+    (0x01db, '\u{0170}'),
+    (0x01de, '\u{0162}'),
+    (0x01e0, '\u{0155}'),
+    (0x01e3, '\u{0103}'),
+    (0x01e5, '\u{013a}'),
+    (0x01e6, '\u{0107}'),
+    (0x01e8, '\u{010d}'),
+    (0x01ea, '\u{0119}'),
+    (0x01ec, '\u{011b}'),
+    (0x01ef, '\u{010f}'),
+    (0x01f0, '\u{0111}'),
+    (0x01f1, '\u{0144}'),
+    (0x01f2, '\u{0148}'),
+    (0x01f5, '\u{0151}'),
+    (0x01f8, '\u{0159}'),
+    (0x01f9, '\u{016f}'),
+    (0x01fb, '\u{0171}'),
+    (0x01fe, '\u{0163}'),
+    (0x01ff, '\u{02d9}'),
+    (0x02a1, '\u{0126}'),
+    (0x02a6, '\u{0124}'),
+    (0x02a9, '\u{0130}'),
+    (0x02ab, '\u{011e}'),
+    (0x02ac, '\u{0134}'),
+    (0x02b1, '\u{0127}'),
+    (0x02b6, '\u{0125}'),
+    (0x02b9, '\u{0131}'),
+    (0x02bb, '\u{011f}'),
+    (0x02bc, '\u{0135}'),
+    (0x02c5, '\u{010a}'),
+    (0x02c6, '\u{0108}'),
+    (0x02d5, '\u{0120}'),
+    (0x02d8, '\u{011c}'),
+    (0x02dd, '\u{016c}'),
+    (0x02de, '\u{015c}'),
+    (0x02e5, '\u{010b}'),
+    (0x02e6, '\u{0109}'),
+    (0x02f5, '\u{0121}'),
+    (0x02f8, '\u{011d}'),
+    (0x02fd, '\u{016d}'),
+    (0x02fe, '\u{015d}'),
+    (0x03a2, '\u{0138}'),
+    (0x03a3, '\u{0156}'),
+    (0x03a5, '\u{0128}'),
+    (0x03a6, '\u{013b}'),
+    (0x03aa, '\u{0112}'),
+    (0x03ab, '\u{0122}'),
+    (0x03ac, '\u{0166}'),
+    (0x03b3, '\u{0157}'),
+    (0x03b5, '\u{0129}'),
+    (0x03b6, '\u{013c}'),
+    (0x03ba, '\u{0113}'),
+    (0x03bb, '\u{0123}'),
+    (0x03bc, '\u{0167}'),
+    (0x03bd, '\u{014a}'),
+    (0x03bf, '\u{014b}'),
+    (0x03c0, '\u{0100}'),
+    (0x03c7, '\u{012e}'),
+    (0x03cc, '\u{0116}'),
+    (0x03cf, '\u{012a}'),
+    (0x03d1, '\u{0145}'),
+    (0x03d2, '\u{014c}'),
+    (0x03d3, '\u{0136}'),
+    (0x03d9, '\u{0172}'),
+    (0x03dd, '\u{0168}'),
+    (0x03de, '\u{016a}'),
+    (0x03e0, '\u{0101}'),
+    (0x03e7, '\u{012f}'),
+    (0x03ec, '\u{0117}'),
+    (0x03ef, '\u{012b}'),
+    (0x03f1, '\u{0146}'),
+    (0x03f2, '\u{014d}'),
+    (0x03f3, '\u{0137}'),
+    (0x03f9, '\u{0173}'),
+    (0x03fd, '\u{0169}'),
+    (0x03fe, '\u{016b}'),
+    (0x047e, '\u{203e}'),
+    (0x04a1, '\u{3002}'),
+    (0x04a2, '\u{300c}'),
+    (0x04a3, '\u{300d}'),
+    (0x04a4, '\u{3001}'),
+    (0x04a5, '\u{30fb}'),
+    (0x04a6, '\u{30f2}'),
+    (0x04a7, '\u{30a1}'),
+    (0x04a8, '\u{30a3}'),
+    (0x04a9, '\u{30a5}'),
+    (0x04aa, '\u{30a7}'),
+    (0x04ab, '\u{30a9}'),
+    (0x04ac, '\u{30e3}'),
+    (0x04ad, '\u{30e5}'),
+    (0x04ae, '\u{30e7}'),
+    (0x04af, '\u{30c3}'),
+    (0x04b0, '\u{30fc}'),
+    (0x04b1, '\u{30a2}'),
+    (0x04b2, '\u{30a4}'),
+    (0x04b3, '\u{30a6}'),
+    (0x04b4, '\u{30a8}'),
+    (0x04b5, '\u{30aa}'),
+    (0x04b6, '\u{30ab}'),
+    (0x04b7, '\u{30ad}'),
+    (0x04b8, '\u{30af}'),
+    (0x04b9, '\u{30b1}'),
+    (0x04ba, '\u{30b3}'),
+    (0x04bb, '\u{30b5}'),
+    (0x04bc, '\u{30b7}'),
+    (0x04bd, '\u{30b9}'),
+    (0x04be, '\u{30bb}'),
+    (0x04bf, '\u{30bd}'),
+    (0x04c0, '\u{30bf}'),
+    (0x04c1, '\u{30c1}'),
+    (0x04c2, '\u{30c4}'),
+    (0x04c3, '\u{30c6}'),
+    (0x04c4, '\u{30c8}'),
+    (0x04c5, '\u{30ca}'),
+    (0x04c6, '\u{30cb}'),
+    (0x04c7, '\u{30cc}'),
+    (0x04c8, '\u{30cd}'),
+    (0x04c9, '\u{30ce}'),
+    (0x04ca, '\u{30cf}'),
+    (0x04cb, '\u{30d2}'),
+    (0x04cc, '\u{30d5}'),
+    (0x04cd, '\u{30d8}'),
+    (0x04ce, '\u{30db}'),
+    (0x04cf, '\u{30de}'),
+    (0x04d0, '\u{30df}'),
+    (0x04d1, '\u{30e0}'),
+    (0x04d2, '\u{30e1}'),
+    (0x04d3, '\u{30e2}'),
+    (0x04d4, '\u{30e4}'),
+    (0x04d5, '\u{30e6}'),
+    (0x04d6, '\u{30e8}'),
+    (0x04d7, '\u{30e9}'),
+    (0x04d8, '\u{30ea}'),
+    (0x04d9, '\u{30eb}'),
+    (0x04da, '\u{30ec}'),
+    (0x04db, '\u{30ed}'),
+    (0x04dc, '\u{30ef}'),
+    (0x04dd, '\u{30f3}'),
+    (0x04de, '\u{309b}'),
+    (0x04df, '\u{309c}'),
+    (0x05ac, '\u{060c}'),
+    (0x05bb, '\u{061b}'),
+    (0x05bf, '\u{061f}'),
+    (0x05c1, '\u{0621}'),
+    (0x05c2, '\u{0622}'),
+    (0x05c3, '\u{0623}'),
+    (0x05c4, '\u{0624}'),
+    (0x05c5, '\u{0625}'),
+    (0x05c6, '\u{0626}'),
+    (0x05c7, '\u{0627}'),
+    (0x05c8, '\u{0628}'),
+    (0x05c9, '\u{0629}'),
+    (0x05ca, '\u{062a}'),
+    (0x05cb, '\u{062b}'),
+    (0x05cc, '\u{062c}'),
+    (0x05cd, '\u{062d}'),
+    (0x05ce, '\u{062e}'),
+    (0x05cf, '\u{062f}'),
+    (0x05d0, '\u{0630}'),
+    (0x05d1, '\u{0631}'),
+    (0x05d2, '\u{0632}'),
+    (0x05d3, '\u{0633}'),
+    (0x05d4, '\u{0634}'),
+    (0x05d5, '\u{0635}'),
+    (0x05d6, '\u{0636}'),
+    (0x05d7, '\u{0637}'),
+    (0x05d8, '\u{0638}'),
+    (0x05d9, '\u{0639}'),
+    (0x05da, '\u{063a}'),
+    (0x05e0, '\u{0640}'),
+    (0x05e1, '\u{0641}'),
+    (0x05e2, '\u{0642}'),
+    (0x05e3, '\u{0643}'),
+    (0x05e4, '\u{0644}'),
+    (0x05e5, '\u{0645}'),
+    (0x05e6, '\u{0646}'),
+    (0x05e7, '\u{0647}'),
+    (0x05e8, '\u{0648}'),
+    (0x05e9, '\u{0649}'),
+    (0x05ea, '\u{064a}'),
+    (0x05eb, '\u{064b}'),
+    (0x05ec, '\u{064c}'),
+    (0x05ed, '\u{064d}'),
+    (0x05ee, '\u{064e}'),
+    (0x05ef, '\u{064f}'),
+    (0x05f0, '\u{0650}'),
+    (0x05f1, '\u{0651}'),
+    (0x05f2, '\u{0652}'),
+    (0x06a1, '\u{0452}'),
+    (0x06a2, '\u{0453}'),
+    (0x06a3, '\u{0451}'),
+    (0x06a4, '\u{0454}'),
+    (0x06a5, '\u{0455}'),
+    (0x06a6, '\u{0456}'),
+    (0x06a7, '\u{0457}'),
+    (0x06a8, '\u{0458}'),
+    (0x06a9, '\u{0459}'),
+    (0x06aa, '\u{045a}'),
+    (0x06ab, '\u{045b}'),
+    (0x06ac, '\u{045c}'),
+    (0x06ad, '\u{0491}'),
+    (0x06ae, '\u{045e}'),
+    (0x06af, '\u{045f}'),
+    (0x06b0, '\u{2116}'),
+    (0x06b1, '\u{0402}'),
+    (0x06b2, '\u{0403}'),
+    (0x06b3, '\u{0401}'),
+    (0x06b4, '\u{0404}'),
+    (0x06b5, '\u{0405}'),
+    (0x06b6, '\u{0406}'),
+    (0x06b7, '\u{0407}'),
+    (0x06b8, '\u{0408}'),
+    (0x06b9, '\u{0409}'),
+    (0x06ba, '\u{040a}'),
+    (0x06bb, '\u{040b}'),
+    (0x06bc, '\u{040c}'),
+    (0x06bd, '\u{0490}'),
+    (0x06be, '\u{040e}'),
+    (0x06bf, '\u{040f}'),
+    (0x06c0, '\u{044e}'),
+    (0x06c1, '\u{0430}'),
+    (0x06c2, '\u{0431}'),
+    (0x06c3, '\u{0446}'),
+    (0x06c4, '\u{0434}'),
+    (0x06c5, '\u{0435}'),
+    (0x06c6, '\u{0444}'),
+    (0x06c7, '\u{0433}'),
+    (0x06c8, '\u{0445}'),
+    (0x06c9, '\u{0438}'),
+    (0x06ca, '\u{0439}'),
+    (0x06cb, '\u{043a}'),
+    (0x06cc, '\u{043b}'),
+    (0x06cd, '\u{043c}'),
+    (0x06ce, '\u{043d}'),
+    (0x06cf, '\u{043e}'),
+    (0x06d0, '\u{043f}'),
+    (0x06d1, '\u{044f}'),
+    (0x06d2, '\u{0440}'),
+    (0x06d3, '\u{0441}'),
+    (0x06d4, '\u{0442}'),
+    (0x06d5, '\u{0443}'),
+    (0x06d6, '\u{0436}'),
+    (0x06d7, '\u{0432}'),
+    (0x06d8, '\u{044c}'),
+    (0x06d9, '\u{044b}'),
+    (0x06da, '\u{0437}'),
+    (0x06db, '\u{0448}'),
+    (0x06dc, '\u{044d}'),
+    (0x06dd, '\u{0449}'),
+    (0x06de, '\u{0447}'),
+    (0x06df, '\u{044a}'),
+    (0x06e0, '\u{042e}'),
+    (0x06e1, '\u{0410}'),
+    (0x06e2, '\u{0411}'),
+    (0x06e3, '\u{0426}'),
+    (0x06e4, '\u{0414}'),
+    (0x06e5, '\u{0415}'),
+    (0x06e6, '\u{0424}'),
+    (0x06e7, '\u{0413}'),
+    (0x06e8, '\u{0425}'),
+    (0x06e9, '\u{0418}'),
+    (0x06ea, '\u{0419}'),
+    (0x06eb, '\u{041a}'),
+    (0x06ec, '\u{041b}'),
+    (0x06ed, '\u{041c}'),
+    (0x06ee, '\u{041d}'),
+    (0x06ef, '\u{041e}'),
+    (0x06f0, '\u{041f}'),
+    (0x06f1, '\u{042f}'),
+    (0x06f2, '\u{0420}'),
+    (0x06f3, '\u{0421}'),
+    (0x06f4, '\u{0422}'),
+    (0x06f5, '\u{0423}'),
+    (0x06f6, '\u{0416}'),
+    (0x06f7, '\u{0412}'),
+    (0x06f8, '\u{042c}'),
+    (0x06f9, '\u{042b}'),
+    (0x06fa, '\u{0417}'),
+    (0x06fb, '\u{0428}'),
+    (0x06fc, '\u{042d}'),
+    (0x06fd, '\u{0429}'),
+    (0x06fe, '\u{0427}'),
+    (0x06ff, '\u{042a}'),
+    (0x07a1, '\u{0386}'),
+    (0x07a2, '\u{0388}'),
+    (0x07a3, '\u{0389}'),
+    (0x07a4, '\u{038a}'),
+    (0x07a5, '\u{03aa}'),
+    (0x07a7, '\u{038c}'),
+    (0x07a8, '\u{038e}'),
+    (0x07a9, '\u{03ab}'),
+    (0x07ab, '\u{038f}'),
+    (0x07ae, '\u{0385}'),
+    (0x07af, '\u{2015}'),
+    (0x07b1, '\u{03ac}'),
+    (0x07b2, '\u{03ad}'),
+    (0x07b3, '\u{03ae}'),
+    (0x07b4, '\u{03af}'),
+    (0x07b5, '\u{03ca}'),
+    (0x07b6, '\u{0390}'),
+    (0x07b7, '\u{03cc}'),
+    (0x07b8, '\u{03cd}'),
+    (0x07b9, '\u{03cb}'),
+    (0x07ba, '\u{03b0}'),
+    (0x07bb, '\u{03ce}'),
+    (0x07c1, '\u{0391}'),
+    (0x07c2, '\u{0392}'),
+    (0x07c3, '\u{0393}'),
+    (0x07c4, '\u{0394}'),
+    (0x07c5, '\u{0395}'),
+    (0x07c6, '\u{0396}'),
+    (0x07c7, '\u{0397}'),
+    (0x07c8, '\u{0398}'),
+    (0x07c9, '\u{0399}'),
+    (0x07ca, '\u{039a}'),
+    (0x07cb, '\u{039b}'),
+    (0x07cc, '\u{039c}'),
+    (0x07cd, '\u{039d}'),
+    (0x07ce, '\u{039e}'),
+    (0x07cf, '\u{039f}'),
+    (0x07d0, '\u{03a0}'),
+    (0x07d1, '\u{03a1}'),
+    (0x07d2, '\u{03a3}'),
+    (0x07d4, '\u{03a4}'),
+    (0x07d5, '\u{03a5}'),
+    (0x07d6, '\u{03a6}'),
+    (0x07d7, '\u{03a7}'),
+    (0x07d8, '\u{03a8}'),
+    (0x07d9, '\u{03a9}'),
+    (0x07e1, '\u{03b1}'),
+    (0x07e2, '\u{03b2}'),
+    (0x07e3, '\u{03b3}'),
+    (0x07e4, '\u{03b4}'),
+    (0x07e5, '\u{03b5}'),
+    (0x07e6, '\u{03b6}'),
+    (0x07e7, '\u{03b7}'),
+    (0x07e8, '\u{03b8}'),
+    (0x07e9, '\u{03b9}'),
+    (0x07ea, '\u{03ba}'),
+    (0x07eb, '\u{03bb}'),
+    (0x07ec, '\u{03bc}'),
+    (0x07ed, '\u{03bd}'),
+    (0x07ee, '\u{03be}'),
+    (0x07ef, '\u{03bf}'),
+    (0x07f0, '\u{03c0}'),
+    (0x07f1, '\u{03c1}'),
+    (0x07f2, '\u{03c3}'),
+    (0x07f3, '\u{03c2}'),
+    (0x07f4, '\u{03c4}'),
+    (0x07f5, '\u{03c5}'),
+    (0x07f6, '\u{03c6}'),
+    (0x07f7, '\u{03c7}'),
+    (0x07f8, '\u{03c8}'),
+    (0x07f9, '\u{03c9}'),
+    (0x08a1, '\u{23b7}'),
+    (0x08a2, '\u{250c}'),
+    (0x08a3, '\u{2500}'),
+    (0x08a4, '\u{2320}'),
+    (0x08a5, '\u{2321}'),
+    (0x08a6, '\u{2502}'),
+    (0x08a7, '\u{23a1}'),
+    (0x08a8, '\u{23a3}'),
+    (0x08a9, '\u{23a4}'),
+    (0x08aa, '\u{23a6}'),
+    (0x08ab, '\u{239b}'),
+    (0x08ac, '\u{239d}'),
+    (0x08ad, '\u{239e}'),
+    (0x08ae, '\u{23a0}'),
+    (0x08af, '\u{23a8}'),
+    (0x08b0, '\u{23ac}'),
+    (0x08bc, '\u{2264}'),
+    (0x08bd, '\u{2260}'),
+    (0x08be, '\u{2265}'),
+    (0x08bf, '\u{222b}'),
+    (0x08c0, '\u{2234}'),
+    (0x08c1, '\u{221d}'),
+    (0x08c2, '\u{221e}'),
+    (0x08c5, '\u{2207}'),
+    (0x08c8, '\u{223c}'),
+    (0x08c9, '\u{2243}'),
+    (0x08cd, '\u{21d4}'),
+    (0x08ce, '\u{21d2}'),
+    (0x08cf, '\u{2261}'),
+    (0x08d6, '\u{221a}'),
+    (0x08da, '\u{2282}'),
+    (0x08db, '\u{2283}'),
+    (0x08dc, '\u{2229}'),
+    (0x08dd, '\u{222a}'),
+    (0x08de, '\u{2227}'),
+    (0x08df, '\u{2228}'),
+    (0x08ef, '\u{2202}'),
+    (0x08f6, '\u{0192}'),
+    (0x08fb, '\u{2190}'),
+    (0x08fc, '\u{2191}'),
+    (0x08fd, '\u{2192}'),
+    (0x08fe, '\u{2193}'),
+    (0x09e0, '\u{25c6}'),
+    (0x09e1, '\u{2592}'),
+    (0x09e2, '\u{2409}'),
+    (0x09e3, '\u{240c}'),
+    (0x09e4, '\u{240d}'),
+    (0x09e5, '\u{240a}'),
+    (0x09e8, '\u{2424}'),
+    (0x09e9, '\u{240b}'),
+    (0x09ea, '\u{2518}'),
+    (0x09eb, '\u{2510}'),
+    (0x09ec, '\u{250c}'),
+    (0x09ed, '\u{2514}'),
+    (0x09ee, '\u{253c}'),
+    (0x09ef, '\u{23ba}'),
+    (0x09f0, '\u{23bb}'),
+    (0x09f1, '\u{2500}'),
+    (0x09f2, '\u{23bc}'),
+    (0x09f3, '\u{23bd}'),
+    (0x09f4, '\u{251c}'),
+    (0x09f5, '\u{2524}'),
+    (0x09f6, '\u{2534}'),
+    (0x09f7, '\u{252c}'),
+    (0x09f8, '\u{2502}'),
+    (0x0aa1, '\u{2003}'),
+    (0x0aa2, '\u{2002}'),
+    (0x0aa3, '\u{2004}'),
+    (0x0aa4, '\u{2005}'),
+    (0x0aa5, '\u{2007}'),
+    (0x0aa6, '\u{2008}'),
+    (0x0aa7, '\u{2009}'),
+    (0x0aa8, '\u{200a}'),
+    (0x0aa9, '\u{2014}'),
+    (0x0aaa, '\u{2013}'),
+    (0x0aac, '\u{2423}'),
+    (0x0aae, '\u{2026}'),
+    (0x0aaf, '\u{2025}'),
+    (0x0ab0, '\u{2153}'),
+    (0x0ab1, '\u{2154}'),
+    (0x0ab2, '\u{2155}'),
+    (0x0ab3, '\u{2156}'),
+    (0x0ab4, '\u{2157}'),
+    (0x0ab5, '\u{2158}'),
+    (0x0ab6, '\u{2159}'),
+    (0x0ab7, '\u{215a}'),
+    (0x0ab8, '\u{2105}'),
+    (0x0abb, '\u{2012}'),
+    (0x0abc, '\u{2329}'),
+    (0x0abd, '\u{002e}'),
+    (0x0abe, '\u{232a}'),
+    (0x0ac3, '\u{215b}'),
+    (0x0ac4, '\u{215c}'),
+    (0x0ac5, '\u{215d}'),
+    (0x0ac6, '\u{215e}'),
+    (0x0ac9, '\u{2122}'),
+    (0x0aca, '\u{2613}'),
+    (0x0acc, '\u{25c1}'),
+    (0x0acd, '\u{25b7}'),
+    (0x0ace, '\u{25cb}'),
+    (0x0acf, '\u{25af}'),
+    (0x0ad0, '\u{2018}'),
+    (0x0ad1, '\u{2019}'),
+    (0x0ad2, '\u{201c}'),
+    (0x0ad3, '\u{201d}'),
+    (0x0ad4, '\u{211e}'),
+    (0x0ad5, '\u{2030}'),
+    (0x0ad6, '\u{2032}'),
+    (0x0ad7, '\u{2033}'),
+    (0x0ad9, '\u{271d}'),
+    (0x0adb, '\u{25ac}'),
+    (0x0adc, '\u{25c0}'),
+    (0x0add, '\u{25b6}'),
+    (0x0ade, '\u{25cf}'),
+    (0x0adf, '\u{25ae}'),
+    (0x0ae0, '\u{25e6}'),
+    (0x0ae1, '\u{25ab}'),
+    (0x0ae2, '\u{25ad}'),
+    (0x0ae3, '\u{25b3}'),
+    (0x0ae4, '\u{25bd}'),
+    (0x0ae5, '\u{2606}'),
+    (0x0ae6, '\u{2022}'),
+    (0x0ae7, '\u{25aa}'),
+    (0x0ae8, '\u{25b2}'),
+    (0x0ae9, '\u{25bc}'),
+    (0x0aea, '\u{261c}'),
+    (0x0aeb, '\u{261e}'),
+    (0x0aec, '\u{2663}'),
+    (0x0aed, '\u{2666}'),
+    (0x0aee, '\u{2665}'),
+    (0x0af0, '\u{2720}'),
+    (0x0af1, '\u{2020}'),
+    (0x0af2, '\u{2021}'),
+    (0x0af3, '\u{2713}'),
+    (0x0af4, '\u{2717}'),
+    (0x0af5, '\u{266f}'),
+    (0x0af6, '\u{266d}'),
+    (0x0af7, '\u{2642}'),
+    (0x0af8, '\u{2640}'),
+    (0x0af9, '\u{260e}'),
+    (0x0afa, '\u{2315}'),
+    (0x0afb, '\u{2117}'),
+    (0x0afc, '\u{2038}'),
+    (0x0afd, '\u{201a}'),
+    (0x0afe, '\u{201e}'),
+    (0x0ba3, '\u{003c}'),
+    (0x0ba6, '\u{003e}'),
+    (0x0ba8, '\u{2228}'),
+    (0x0ba9, '\u{2227}'),
+    (0x0bc0, '\u{00af}'),
+    (0x0bc2, '\u{22a4}'),
+    (0x0bc3, '\u{2229}'),
+    (0x0bc4, '\u{230a}'),
+    (0x0bc6, '\u{005f}'),
+    (0x0bca, '\u{2218}'),
+    (0x0bcc, '\u{2395}'),
+    (0x0bce, '\u{22a5}'),
+    (0x0bcf, '\u{25cb}'),
+    (0x0bd3, '\u{2308}'),
+    (0x0bd6, '\u{222a}'),
+    (0x0bd8, '\u{2283}'),
+    (0x0bda, '\u{2282}'),
+    (0x0bdc, '\u{22a3}'),
+    (0x0bfc, '\u{22a2}'),
+    (0x0cdf, '\u{2017}'),
+    (0x0ce0, '\u{05d0}'),
+    (0x0ce1, '\u{05d1}'),
+    (0x0ce2, '\u{05d2}'),
+    (0x0ce3, '\u{05d3}'),
+    (0x0ce4, '\u{05d4}'),
+    (0x0ce5, '\u{05d5}'),
+    (0x0ce6, '\u{05d6}'),
+    (0x0ce7, '\u{05d7}'),
+    (0x0ce8, '\u{05d8}'),
+    (0x0ce9, '\u{05d9}'),
+    (0x0cea, '\u{05da}'),
+    (0x0ceb, '\u{05db}'),
+    (0x0cec, '\u{05dc}'),
+    (0x0ced, '\u{05dd}'),
+    (0x0cee, '\u{05de}'),
+    (0x0cef, '\u{05df}'),
+    (0x0cf0, '\u{05e0}'),
+    (0x0cf1, '\u{05e1}'),
+    (0x0cf2, '\u{05e2}'),
+    (0x0cf3, '\u{05e3}'),
+    (0x0cf4, '\u{05e4}'),
+    (0x0cf5, '\u{05e5}'),
+    (0x0cf6, '\u{05e6}'),
+    (0x0cf7, '\u{05e7}'),
+    (0x0cf8, '\u{05e8}'),
+    (0x0cf9, '\u{05e9}'),
+    (0x0cfa, '\u{05ea}'),
+    (0x0da1, '\u{0e01}'),
+    (0x0da2, '\u{0e02}'),
+    (0x0da3, '\u{0e03}'),
+    (0x0da4, '\u{0e04}'),
+    (0x0da5, '\u{0e05}'),
+    (0x0da6, '\u{0e06}'),
+    (0x0da7, '\u{0e07}'),
+    (0x0da8, '\u{0e08}'),
+    (0x0da9, '\u{0e09}'),
+    (0x0daa, '\u{0e0a}'),
+    (0x0dab, '\u{0e0b}'),
+    (0x0dac, '\u{0e0c}'),
+    (0x0dad, '\u{0e0d}'),
+    (0x0dae, '\u{0e0e}'),
+    (0x0daf, '\u{0e0f}'),
+    (0x0db0, '\u{0e10}'),
+    (0x0db1, '\u{0e11}'),
+    (0x0db2, '\u{0e12}'),
+    (0x0db3, '\u{0e13}'),
+    (0x0db4, '\u{0e14}'),
+    (0x0db5, '\u{0e15}'),
+    (0x0db6, '\u{0e16}'),
+    (0x0db7, '\u{0e17}'),
+    (0x0db8, '\u{0e18}'),
+    (0x0db9, '\u{0e19}'),
+    (0x0dba, '\u{0e1a}'),
+    (0x0dbb, '\u{0e1b}'),
+    (0x0dbc, '\u{0e1c}'),
+    (0x0dbd, '\u{0e1d}'),
+    (0x0dbe, '\u{0e1e}'),
+    (0x0dbf, '\u{0e1f}'),
+    (0x0dc0, '\u{0e20}'),
+    (0x0dc1, '\u{0e21}'),
+    (0x0dc2, '\u{0e22}'),
+    (0x0dc3, '\u{0e23}'),
+    (0x0dc4, '\u{0e24}'),
+    (0x0dc5, '\u{0e25}'),
+    (0x0dc6, '\u{0e26}'),
+    (0x0dc7, '\u{0e27}'),
+    (0x0dc8, '\u{0e28}'),
+    (0x0dc9, '\u{0e29}'),
+    (0x0dca, '\u{0e2a}'),
+    (0x0dcb, '\u{0e2b}'),
+    (0x0dcc, '\u{0e2c}'),
+    (0x0dcd, '\u{0e2d}'),
+    (0x0dce, '\u{0e2e}'),
+    (0x0dcf, '\u{0e2f}'),
+    (0x0dd0, '\u{0e30}'),
+    (0x0dd1, '\u{0e31}'),
+    (0x0dd2, '\u{0e32}'),
+    (0x0dd3, '\u{0e33}'),
+    (0x0dd4, '\u{0e34}'),
+    (0x0dd5, '\u{0e35}'),
+    (0x0dd6, '\u{0e36}'),
+    (0x0dd7, '\u{0e37}'),
+    (0x0dd8, '\u{0e38}'),
+    (0x0dd9, '\u{0e39}'),
+    (0x0dda, '\u{0e3a}'),
+    (0x0ddf, '\u{0e3f}'),
+    (0x0de0, '\u{0e40}'),
+    (0x0de1, '\u{0e41}'),
+    (0x0de2, '\u{0e42}'),
+    (0x0de3, '\u{0e43}'),
+    (0x0de4, '\u{0e44}'),
+    (0x0de5, '\u{0e45}'),
+    (0x0de6, '\u{0e46}'),
+    (0x0de7, '\u{0e47}'),
+    (0x0de8, '\u{0e48}'),
+    (0x0de9, '\u{0e49}'),
+    (0x0dea, '\u{0e4a}'),
+    (0x0deb, '\u{0e4b}'),
+    (0x0dec, '\u{0e4c}'),
+    (0x0ded, '\u{0e4d}'),
+    (0x0df0, '\u{0e50}'),
+    (0x0df1, '\u{0e51}'),
+    (0x0df2, '\u{0e52}'),
+    (0x0df3, '\u{0e53}'),
+    (0x0df4, '\u{0e54}'),
+    (0x0df5, '\u{0e55}'),
+    (0x0df6, '\u{0e56}'),
+    (0x0df7, '\u{0e57}'),
+    (0x0df8, '\u{0e58}'),
+    (0x0df9, '\u{0e59}'),
+    (0x0ea1, '\u{3131}'),
+    (0x0ea2, '\u{3132}'),
+    (0x0ea3, '\u{3133}'),
+    (0x0ea4, '\u{3134}'),
+    (0x0ea5, '\u{3135}'),
+    (0x0ea6, '\u{3136}'),
+    (0x0ea7, '\u{3137}'),
+    (0x0ea8, '\u{3138}'),
+    (0x0ea9, '\u{3139}'),
+    (0x0eaa, '\u{313a}'),
+    (0x0eab, '\u{313b}'),
+    (0x0eac, '\u{313c}'),
+    (0x0ead, '\u{313d}'),
+    (0x0eae, '\u{313e}'),
+    (0x0eaf, '\u{313f}'),
+    (0x0eb0, '\u{3140}'),
+    (0x0eb1, '\u{3141}'),
+    (0x0eb2, '\u{3142}'),
+    (0x0eb3, '\u{3143}'),
+    (0x0eb4, '\u{3144}'),
+    (0x0eb5, '\u{3145}'),
+    (0x0eb6, '\u{3146}'),
+    (0x0eb7, '\u{3147}'),
+    (0x0eb8, '\u{3148}'),
+    (0x0eb9, '\u{3149}'),
+    (0x0eba, '\u{314a}'),
+    (0x0ebb, '\u{314b}'),
+    (0x0ebc, '\u{314c}'),
+    (0x0ebd, '\u{314d}'),
+    (0x0ebe, '\u{314e}'),
+    (0x0ebf, '\u{314f}'),
+    (0x0ec0, '\u{3150}'),
+    (0x0ec1, '\u{3151}'),
+    (0x0ec2, '\u{3152}'),
+    (0x0ec3, '\u{3153}'),
+    (0x0ec4, '\u{3154}'),
+    (0x0ec5, '\u{3155}'),
+    (0x0ec6, '\u{3156}'),
+    (0x0ec7, '\u{3157}'),
+    (0x0ec8, '\u{3158}'),
+    (0x0ec9, '\u{3159}'),
+    (0x0eca, '\u{315a}'),
+    (0x0ecb, '\u{315b}'),
+    (0x0ecc, '\u{315c}'),
+    (0x0ecd, '\u{315d}'),
+    (0x0ece, '\u{315e}'),
+    (0x0ecf, '\u{315f}'),
+    (0x0ed0, '\u{3160}'),
+    (0x0ed1, '\u{3161}'),
+    (0x0ed2, '\u{3162}'),
+    (0x0ed3, '\u{3163}'),
+    (0x0ed4, '\u{11a8}'),
+    (0x0ed5, '\u{11a9}'),
+    (0x0ed6, '\u{11aa}'),
+    (0x0ed7, '\u{11ab}'),
+    (0x0ed8, '\u{11ac}'),
+    (0x0ed9, '\u{11ad}'),
+    (0x0eda, '\u{11ae}'),
+    (0x0edb, '\u{11af}'),
+    (0x0edc, '\u{11b0}'),
+    (0x0edd, '\u{11b1}'),
+    (0x0ede, '\u{11b2}'),
+    (0x0edf, '\u{11b3}'),
+    (0x0ee0, '\u{11b4}'),
+    (0x0ee1, '\u{11b5}'),
+    (0x0ee2, '\u{11b6}'),
+    (0x0ee3, '\u{11b7}'),
+    (0x0ee4, '\u{11b8}'),
+    (0x0ee5, '\u{11b9}'),
+    (0x0ee6, '\u{11ba}'),
+    (0x0ee7, '\u{11bb}'),
+    (0x0ee8, '\u{11bc}'),
+    (0x0ee9, '\u{11bd}'),
+    (0x0eea, '\u{11be}'),
+    (0x0eeb, '\u{11bf}'),
+    (0x0eec, '\u{11c0}'),
+    (0x0eed, '\u{11c1}'),
+    (0x0eee, '\u{11c2}'),
+    (0x0eef, '\u{316d}'),
+    (0x0ef0, '\u{3171}'),
+    (0x0ef1, '\u{3178}'),
+    (0x0ef2, '\u{317f}'),
+    (0x0ef3, '\u{3181}'),
+    (0x0ef4, '\u{3184}'),
+    (0x0ef5, '\u{3186}'),
+    (0x0ef6, '\u{318d}'),
+    (0x0ef7, '\u{318e}'),
+    (0x0ef8, '\u{11eb}'),
+    (0x0ef9, '\u{11f0}'),
+    (0x0efa, '\u{11f9}'),
+    (0x0eff, '\u{20a9}'),
+    (0x13bc, '\u{0152}'),
+    (0x13bd, '\u{0153}'),
+    (0x13be, '\u{0178}'),
+    (0x20ac, '\u{20ac}'),
+    (0xff09, '\u{0009}'),
+    (0xff0d, '\u{000a}'),
+];
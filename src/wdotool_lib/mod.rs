@@ -1,19 +1,35 @@
 pub mod app_data;
 pub mod helper;
+pub mod image;
+pub mod keysyms;
 pub mod screencopy;
 pub mod shm;
 pub mod virtual_keyboard;
 pub mod virtual_pointer;
+pub mod xdg_output;
+pub mod xkb;
+
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::{Context, Result};
 use app_data::AppData;
-use helper::{connect_wayland, screenshot, setup_virtual_keyboard};
+use helper::{
+    capture, capture_output, connect_wayland, dispatch_until, roundtrip, setup_virtual_keyboard,
+};
 use ndarray::prelude::*;
 use rand_distr::{Distribution, Normal};
 use virtual_keyboard::zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1;
 use virtual_pointer::zwlr_virtual_pointer_v1::ZwlrVirtualPointerV1;
-use wayland_client::{protocol::wl_pointer, EventQueue, QueueHandle};
+use wayland_client::{
+    protocol::{wl_output, wl_pointer, wl_shm},
+    EventQueue, Proxy, QueueHandle,
+};
 
+#[derive(Clone, Copy)]
 pub enum UIntValue {
     UInt(u32),
     UIntRange(u32, u32),
@@ -35,16 +51,276 @@ impl UIntValue {
     }
 }
 
+#[derive(Clone, Copy)]
+pub enum FloatValue {
+    Float(f64),
+    FloatRange(f64, f64),
+}
+
+impl FloatValue {
+    pub fn get(&self) -> Result<f64> {
+        match self {
+            FloatValue::Float(value) => Ok(*value),
+            FloatValue::FloatRange(min, max) => {
+                let mean = (min + max) / 2.;
+                let std_dev = (max - min) / 2.;
+                let normal = Normal::new(mean, std_dev).context("invalid normal distribution")?;
+                let v = normal.sample(&mut rand::thread_rng());
+                Ok(v.max(*min).min(*max))
+            }
+        }
+    }
+}
+
+/// Position of the virtual pointer, in the coordinates of the last absolute motion
+#[derive(Clone, Debug, PartialEq)]
+pub struct MousePosition {
+    pub x: f64,
+    pub y: f64,
+    pub x_extent: u32,
+    pub y_extent: u32,
+    /// output the coordinates are relative to, if any
+    pub screen_name: Option<String>,
+}
+
+/// A rectangle of an output, in logical coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Region {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl From<(i32, i32, i32, i32)> for Region {
+    fn from((x, y, width, height): (i32, i32, i32, i32)) -> Self {
+        Region {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Errors after which the same call can be retried
+#[derive(Debug)]
+pub enum RetryableError {
+    /// the compositor didn't answer within the timeout
+    Timeout(Duration),
+    /// the compositor couldn't copy the frame, e.g. because the output changed
+    ScreencopyFailed,
+}
+
+impl fmt::Display for RetryableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RetryableError::Timeout(timeout) => {
+                write!(f, "the compositor didn't answer within {timeout:?}")
+            }
+            RetryableError::ScreencopyFailed => {
+                write!(f, "the compositor failed to copy the frame")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RetryableError {}
+
+/// Pixels of a screenshot, in the shm format chosen for the capture
+pub struct Screenshot {
+    /// array of shape (height, width, bytes per pixel), first row on top
+    pub pixels: Array3<u8>,
+    pub format: wl_shm::Format,
+}
+
+impl Screenshot {
+    /// Pixels in the given channel order, whatever the format of the capture
+    pub fn convert(&self, order: image::ChannelOrder) -> Result<Array3<u8>> {
+        image::convert(self.pixels.view(), self.format, order)
+    }
+}
+
+/// A capture whose pixels stay in the shm buffer the compositor copied them into
+///
+/// The buffer isn't reused by other captures while the frame is alive.
+pub struct Frame {
+    mmap: Arc<shm::Mmap>,
+    pub format: wl_shm::Format,
+    pub width: u32,
+    pub height: u32,
+    /// bytes between the start of two rows, which may be padded
+    pub stride: u32,
+    /// rows are stored bottom to top
+    pub y_invert: bool,
+    /// presentation time of the content, from a clock with an arbitrary offset (wlroots
+    /// uses CLOCK_MONOTONIC)
+    pub timestamp: Duration,
+    /// rectangles that changed since the previous capture of the output, in pixels, when
+    /// captured with damage tracking
+    pub damage: Option<Vec<Region>>,
+    /// output the frame was captured from
+    pub output_name: Option<String>,
+    /// scale and transform of the output at the time of the capture
+    pub scale: i32,
+    pub transform: wl_output::Transform,
+}
+
+impl Frame {
+    pub fn bytes_per_pixel(&self) -> usize {
+        image::bytes_per_pixel(self.format)
+    }
+
+    /// View of shape (height, width, bytes per pixel) over the shm buffer, first row on top
+    pub fn pixels(&self) -> ArrayView3<'_, u8> {
+        let bytes_per_pixel = self.bytes_per_pixel();
+        let shape = (self.height as usize, self.width as usize, bytes_per_pixel).strides((
+            self.stride as usize,
+            bytes_per_pixel,
+            1,
+        ));
+        let mut pixels = ArrayView3::from_shape(shape, self.mmap.as_slice())
+            .expect("shm buffers hold stride * height bytes");
+        if self.y_invert {
+            pixels.invert_axis(Axis(0));
+        }
+        pixels
+    }
+
+    /// Pixels in the given channel order, whatever the format of the capture
+    pub fn convert(&self, order: image::ChannelOrder) -> Result<Array3<u8>> {
+        image::convert(self.pixels(), self.format, order)
+    }
+
+    /// Like [`Frame::convert`], but writes into `out` instead of allocating
+    pub fn convert_into(&self, order: image::ChannelOrder, out: ArrayViewMut3<u8>) -> Result<()> {
+        image::convert_into(self.pixels(), self.format, order, out)
+    }
+
+    /// Copy of the pixels, without the row padding
+    pub fn to_screenshot(&self) -> Screenshot {
+        Screenshot {
+            pixels: self.pixels().to_owned(),
+            format: self.format,
+        }
+    }
+}
+
+/// A key, either as a raw evdev keycode or as a chord like `ctrl+shift+t`
+#[derive(Clone)]
+pub enum KeyValue {
+    Code(u32),
+    Chord(String),
+}
+
+/// A pointer button, see BTN_* in linux/input-event-codes.h
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    Side,
+    Extra,
+    Forward,
+    Back,
+    Task,
+    /// raw evdev code of the button
+    Code(u32),
+}
+
+impl MouseButton {
+    /// evdev code of the button
+    pub fn code(&self) -> u32 {
+        match self {
+            MouseButton::Left => 0x110,
+            MouseButton::Right => 0x111,
+            MouseButton::Middle => 0x112,
+            MouseButton::Side => 0x113,
+            MouseButton::Extra => 0x114,
+            MouseButton::Forward => 0x115,
+            MouseButton::Back => 0x116,
+            MouseButton::Task => 0x117,
+            MouseButton::Code(code) => *code,
+        }
+    }
+}
+
+impl std::str::FromStr for MouseButton {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "left" => Ok(MouseButton::Left),
+            "right" => Ok(MouseButton::Right),
+            "middle" => Ok(MouseButton::Middle),
+            "side" => Ok(MouseButton::Side),
+            "extra" => Ok(MouseButton::Extra),
+            "forward" => Ok(MouseButton::Forward),
+            "back" => Ok(MouseButton::Back),
+            "task" => Ok(MouseButton::Task),
+            _ => anyhow::bail!("unknown mouse button {name:?}"),
+        }
+    }
+}
+
+/// How a scroll is sent to the compositor
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScrollMode {
+    /// discrete mouse wheel clicks, the values are numbers of clicks
+    Wheel,
+    /// continuous scroll, the values are in pixels
+    Smooth,
+}
+
+impl std::str::FromStr for ScrollMode {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "wheel" => Ok(ScrollMode::Wheel),
+            "smooth" => Ok(ScrollMode::Smooth),
+            _ => anyhow::bail!("unknown scroll mode {name:?}"),
+        }
+    }
+}
+
+// value of one mouse wheel click, as sent by libinput
+const WHEEL_CLICK_VALUE: f64 = 15.;
+
+/// How long to wait for the compositor before giving up
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Delay between two captures when waiting for a change without damage tracking
+const CHANGE_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
 pub struct Wdotool {
     app_data: AppData,
     event_queue: EventQueue<AppData>,
     queue_handle: QueueHandle<AppData>,
-    keyboard: ZwpVirtualKeyboardV1,
+    /// None if the compositor didn't send a xkb keymap
+    keyboard: Option<ZwpVirtualKeyboardV1>,
     pointer: ZwlrVirtualPointerV1,
+    /// None if the keymap couldn't be parsed
+    keymap: Option<xkb::Keymap>,
+    modifiers: xkb::ModifiersState,
+    start: std::time::Instant,
+    /// last known position of the pointer, if it was ever moved to an absolute position
+    position: Option<MousePosition>,
+    /// how long to wait for the compositor before failing with [`RetryableError::Timeout`]
+    timeout: Duration,
 }
 
 impl Wdotool {
     pub fn connect(wayland_display: Option<&str>) -> Result<Wdotool> {
+        Self::connect_with_timeout(wayland_display, DEFAULT_TIMEOUT)
+    }
+
+    /// Like [`Wdotool::connect`], with the timeout of every wait for the compositor,
+    /// including the ones made while connecting
+    pub fn connect_with_timeout(
+        wayland_display: Option<&str>,
+        timeout: Duration,
+    ) -> Result<Wdotool> {
         let connection = connect_wayland(wayland_display)?;
         let display = connection.display();
         let mut event_queue = connection.new_event_queue();
@@ -52,11 +328,14 @@ impl Wdotool {
 
         // Call the registry to get global objects
         display.get_registry(&queue_handle, ());
-        let mut app_data = AppData::default();
-        event_queue.roundtrip(&mut app_data).unwrap();
+        let mut app_data = AppData {
+            display: Some(display),
+            ..Default::default()
+        };
+        roundtrip(&mut app_data, &queue_handle, &mut event_queue, timeout)?;
 
-        let (mut app_data, keyboard) =
-            setup_virtual_keyboard(app_data, &queue_handle, &mut event_queue);
+        let (mut app_data, keyboard, keymap) =
+            setup_virtual_keyboard(app_data, &queue_handle, &mut event_queue, timeout)?;
 
         // Virtual pointer
         let pointer = app_data.vpm.as_ref().unwrap().create_virtual_pointer(
@@ -64,7 +343,7 @@ impl Wdotool {
             &queue_handle,
             (),
         );
-        event_queue.roundtrip(&mut app_data).unwrap();
+        roundtrip(&mut app_data, &queue_handle, &mut event_queue, timeout)?;
 
         Ok(Wdotool {
             app_data,
@@ -72,75 +351,663 @@ impl Wdotool {
             queue_handle,
             keyboard,
             pointer,
+            keymap,
+            modifiers: xkb::ModifiersState::default(),
+            start: std::time::Instant::now(),
+            position: None,
+            timeout,
         })
     }
 
+    /// Set how long every wait for the compositor may take
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Send the pending requests and wait until the compositor processed them
+    fn roundtrip(&mut self) -> Result<()> {
+        roundtrip(
+            &mut self.app_data,
+            &self.queue_handle,
+            &mut self.event_queue,
+            self.timeout,
+        )
+    }
+
+    /// Outputs of the compositor, sorted by name
+    pub fn outputs(&self) -> Vec<&app_data::Output> {
+        let mut outputs: Vec<&app_data::Output> = self.app_data.outputs.values().collect();
+        outputs.sort_by(|a, b| a.name.cmp(&b.name));
+        outputs
+    }
+
+    /// Process the pending events of the compositor, so that added, removed or changed
+    /// outputs are taken into account
+    pub fn refresh_outputs(&mut self) -> Result<()> {
+        self.roundtrip()?;
+        self.wait_ouput_detected()
+    }
+
+    /// Counter incremented each time an output is added, removed or changed
+    pub fn outputs_generation(&mut self) -> Result<u64> {
+        self.refresh_outputs()?;
+        Ok(self.app_data.outputs_generation)
+    }
+
     pub fn wait_ouput_detected(&mut self) -> Result<()> {
-        while !self.app_data.all_output_name_set() {
-            self.event_queue.blocking_dispatch(&mut self.app_data)?;
+        dispatch_until(
+            &mut self.app_data,
+            &mut self.event_queue,
+            self.timeout,
+            AppData::all_output_name_set,
+        )
+    }
+
+    /// Capture an output, or only a region of it
+    ///
+    /// The region is in the logical coordinates of the output (see xdg-output) and is
+    /// clipped to the output. The returned array is in pixels, so its size differs from
+    /// the region on scaled outputs.
+    ///
+    /// With `include_cursor`, the cursor is composited onto the frame.
+    pub fn screenshot(
+        &mut self,
+        screen_name: Option<&str>,
+        region: Option<Region>,
+        include_cursor: bool,
+    ) -> Result<Screenshot> {
+        let frame = self.capture(screen_name, region, include_cursor)?;
+        Ok(frame.to_screenshot())
+    }
+
+    /// Shape (height, width, channels) of the array [`Wdotool::screenshot_into`] expects
+    ///
+    /// Screencopy frames are not rotated, so this is the size of the current mode, before
+    /// the transform of the output.
+    pub fn screenshot_shape(
+        &mut self,
+        screen_name: Option<&str>,
+        order: image::ChannelOrder,
+    ) -> Result<(usize, usize, usize)> {
+        self.refresh_outputs()?;
+        let (id, _) = capture_output(&self.app_data, screen_name)?;
+        let mode = self.app_data.outputs[&id]
+            .mode
+            .as_ref()
+            .context("the mode of the output is unknown")?;
+        Ok((mode.height as usize, mode.width as usize, order.channels()))
+    }
+
+    /// Capture a whole output into a preallocated array of shape (height, width, channels)
+    ///
+    /// The shape is checked before capturing.
+    pub fn screenshot_into(
+        &mut self,
+        out: &mut ArrayViewMut3<u8>,
+        screen_name: Option<&str>,
+        order: image::ChannelOrder,
+    ) -> Result<()> {
+        let shape = self.screenshot_shape(screen_name, order)?;
+        if out.dim() != shape {
+            anyhow::bail!(
+                "expected an array of shape {shape:?} for {order:?}, got {:?}",
+                out.dim()
+            );
         }
-        Ok(())
+        let frame = self.capture(screen_name, None, false)?;
+        frame.convert_into(order, out.view_mut())
     }
 
-    pub fn screenshot(&mut self, screen_name: Option<&str>) -> Result<Array3<u8>> {
-        let array = screenshot(
+    /// Like [`Wdotool::screenshot`], but the pixels are left in the shm buffer
+    pub fn capture(
+        &mut self,
+        screen_name: Option<&str>,
+        region: Option<Region>,
+        include_cursor: bool,
+    ) -> Result<Frame> {
+        self.refresh_outputs()?;
+        capture(
             &mut self.app_data,
             &self.queue_handle,
             &mut self.event_queue,
             screen_name,
-        )?;
-        Ok(array)
+            region,
+            include_cursor,
+            false,
+            self.timeout,
+        )
     }
 
+    /// Wait until the content of the output changes, then capture it
+    ///
+    /// The damage of the frame lists the rectangles that changed since the previous
+    /// capture with damage of the output. The first one is usually fully damaged.
+    /// Fails with [`RetryableError::Timeout`] if nothing changes within the timeout.
+    pub fn capture_damage(
+        &mut self,
+        screen_name: Option<&str>,
+        region: Option<Region>,
+        include_cursor: bool,
+    ) -> Result<Frame> {
+        self.refresh_outputs()?;
+        capture(
+            &mut self.app_data,
+            &self.queue_handle,
+            &mut self.event_queue,
+            screen_name,
+            region,
+            include_cursor,
+            true,
+            self.timeout,
+        )
+    }
+
+    /// Wait until the content of the output (or of a region of it) differs from what it
+    /// is now, and return the new content
+    ///
+    /// Returns None if nothing changed within `timeout`. The cursor is ignored.
+    pub fn wait_for_change(
+        &mut self,
+        screen_name: Option<&str>,
+        region: Option<Region>,
+        timeout: Duration,
+    ) -> Result<Option<Frame>> {
+        let deadline = Instant::now() + timeout;
+        let baseline = self.capture(screen_name, region, false)?;
+        self.next_change(screen_name, region, &baseline, deadline)
+    }
+
+    /// Wait until the content of the output (or of a region of it) didn't change for
+    /// `quiet`, and return this stable content
+    ///
+    /// Returns None if the content kept changing for `timeout`.
+    pub fn wait_until_stable(
+        &mut self,
+        screen_name: Option<&str>,
+        region: Option<Region>,
+        quiet: Duration,
+        timeout: Duration,
+    ) -> Result<Option<Frame>> {
+        let deadline = Instant::now() + timeout;
+        let mut stable = self.capture(screen_name, region, false)?;
+        loop {
+            let quiet_end = Instant::now() + quiet;
+            if quiet_end > deadline {
+                return Ok(None);
+            }
+            match self.next_change(screen_name, region, &stable, quiet_end)? {
+                Some(frame) => stable = frame,
+                None => return Ok(Some(stable)),
+            }
+        }
+    }
+
+    /// Capture until the content differs from `baseline`, or return None at `deadline`
+    ///
+    /// With damage tracking, the compositor only answers once something changed, else
+    /// the output is polled.
+    fn next_change(
+        &mut self,
+        screen_name: Option<&str>,
+        region: Option<Region>,
+        baseline: &Frame,
+        deadline: Instant,
+    ) -> Result<Option<Frame>> {
+        let with_damage = self
+            .app_data
+            .screencopy_manager
+            .as_ref()
+            .is_some_and(|manager| manager.version() >= 2);
+        loop {
+            if !with_damage {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                std::thread::sleep(CHANGE_POLL_INTERVAL.min(remaining));
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+
+            // the damage may be older than the baseline, so the content is compared too
+            let frame = match capture(
+                &mut self.app_data,
+                &self.queue_handle,
+                &mut self.event_queue,
+                screen_name,
+                region,
+                false,
+                with_damage,
+                remaining,
+            ) {
+                Ok(frame) => frame,
+                Err(err) if matches!(err.downcast_ref(), Some(RetryableError::Timeout(_))) => {
+                    return Ok(None)
+                }
+                Err(err) => return Err(err),
+            };
+            if frame.pixels() != baseline.pixels() {
+                return Ok(Some(frame));
+            }
+        }
+    }
+
+    /// Virtual pointer bound to an output, created the first time the output is used
+    fn output_pointer(&mut self, screen_name: &str) -> Result<ZwlrVirtualPointerV1> {
+        let vpm = self
+            .app_data
+            .vpm
+            .clone()
+            .context("no virtual pointer manager")?;
+        let seat = self.app_data.seat.clone();
+        let output = self
+            .app_data
+            .get_output_mut_by_name(screen_name)
+            .context(format!("no WLOutput with name {screen_name}"))?;
+        if let Some(pointer) = &output.pointer {
+            return Ok(pointer.clone());
+        }
+
+        if vpm.version() < 2 {
+            anyhow::bail!("the compositor doesn't support output-bound virtual pointers")
+        }
+        let pointer = vpm.create_virtual_pointer_with_output(
+            seat.as_ref(),
+            Some(&output.output),
+            &self.queue_handle,
+            (),
+        );
+        output.pointer = Some(pointer.clone());
+        self.roundtrip()?;
+        Ok(pointer)
+    }
+
+    /// Move the pointer to `x`, `y`, in a space of size `x_extent` by `y_extent`
+    ///
+    /// When `screen_name` is given, the coordinates are relative to this output and the
+    /// extents default to its size in pixels, so that they match the screenshots.
+    /// Otherwise the coordinates span all the outputs and the extents default to their
+    /// bounding box.
     pub fn move_mouse(
         &mut self,
         x: UIntValue,
         y: UIntValue,
-        x_extent: u32,
-        y_extent: u32,
+        x_extent: Option<u32>,
+        y_extent: Option<u32>,
+        screen_name: Option<&str>,
     ) -> Result<()> {
         let x = x.get()?;
         let y = y.get()?;
-        self.pointer.motion_absolute(0, x, y, x_extent, y_extent);
-        self.event_queue.roundtrip(&mut self.app_data)?;
+        let (pointer, size) = match screen_name {
+            Some(screen_name) => {
+                self.refresh_outputs()?;
+                let pointer = self.output_pointer(screen_name)?;
+                let size = self
+                    .app_data
+                    .get_output_mut_by_name(screen_name)
+                    .and_then(|output| output.pixel_size())
+                    .map(|(width, height)| (width as u32, height as u32));
+                (pointer, size)
+            }
+            None => (self.pointer.clone(), self.app_data.layout_size()),
+        };
+        let (x_extent, y_extent) = match (x_extent, y_extent) {
+            (Some(x_extent), Some(y_extent)) => (x_extent, y_extent),
+            _ => {
+                let (width, height) =
+                    size.context("the size of the outputs is unknown, please give the extents")?;
+                (x_extent.unwrap_or(width), y_extent.unwrap_or(height))
+            }
+        };
+        pointer.motion_absolute(self.time(), x, y, x_extent, y_extent);
+        self.roundtrip()?;
+        self.position = Some(MousePosition {
+            x: x as f64,
+            y: y as f64,
+            x_extent,
+            y_extent,
+            screen_name: screen_name.map(|name| name.to_string()),
+        });
         Ok(())
     }
 
-    pub fn left_click(&mut self, duration_ms: UIntValue) -> Result<()> {
-        let duration_ms = duration_ms.get()?;
+    /// Move the pointer by `dx`, `dy` pixels, with sub-pixel precision
+    ///
+    /// The compositor may apply pointer acceleration to relative motions.
+    pub fn move_mouse_relative(&mut self, dx: FloatValue, dy: FloatValue) -> Result<()> {
+        let dx = dx.get()?;
+        let dy = dy.get()?;
+        self.pointer.motion(self.time(), dx, dy);
+        self.pointer.frame();
+        self.roundtrip()?;
+        if let Some(position) = self.position.take() {
+            self.position = self.moved_position(position, dx, dy);
+        }
+        Ok(())
+    }
+
+    /// Position after a relative motion of `dx`, `dy` compositor pixels, or None if it
+    /// can't be expressed in the coordinates of `position` anymore
+    fn moved_position(
+        &self,
+        mut position: MousePosition,
+        dx: f64,
+        dy: f64,
+    ) -> Option<MousePosition> {
+        // size of the space the extents are mapped onto, in compositor pixels
+        let (width, height) = match &position.screen_name {
+            Some(screen_name) => self
+                .app_data
+                .outputs
+                .values()
+                .find(|output| output.name.as_deref() == Some(screen_name))?
+                .logical_size()?,
+            None => {
+                let (width, height) = self.app_data.layout_size()?;
+                (width as i32, height as i32)
+            }
+        };
+        if width <= 0 || height <= 0 || position.x_extent == 0 || position.y_extent == 0 {
+            return None;
+        }
+
+        let x = position.x + dx * position.x_extent as f64 / width as f64;
+        let y = position.y + dy * position.y_extent as f64 / height as f64;
+        let x_max = position.x_extent as f64 - 1.;
+        let y_max = position.y_extent as f64 - 1.;
+        if position.screen_name.is_some() {
+            // the pointer left the output
+            if !(0. ..=x_max).contains(&x) || !(0. ..=y_max).contains(&y) {
+                return None;
+            }
+            (position.x, position.y) = (x, y);
+        } else {
+            // the compositor keeps the pointer inside the outputs
+            (position.x, position.y) = (x.clamp(0., x_max), y.clamp(0., y_max));
+        }
+        Some(position)
+    }
+
+    /// Last position the pointer was moved to by this `Wdotool`
+    ///
+    /// `None` until the first absolute motion, as the initial position of the pointer is
+    /// unknown. Relative motions are tracked without the acceleration the compositor may
+    /// apply, and the position becomes `None` when they leave the output it is relative to.
+    pub fn get_mouse_position(&self) -> Option<MousePosition> {
+        self.position.clone()
+    }
+
+    /// Press a pointer button without releasing it
+    pub fn button_down(&mut self, button: MouseButton) -> Result<()> {
         self.pointer
-            .button(0, 272, wl_pointer::ButtonState::Pressed);
-        self.event_queue.roundtrip(&mut self.app_data)?;
+            .button(self.time(), button.code(), wl_pointer::ButtonState::Pressed);
+        self.roundtrip()?;
+        Ok(())
+    }
+
+    /// Release a pointer button
+    pub fn button_up(&mut self, button: MouseButton) -> Result<()> {
+        self.pointer.button(
+            self.time(),
+            button.code(),
+            wl_pointer::ButtonState::Released,
+        );
+        self.roundtrip()?;
+        Ok(())
+    }
+
+    /// Press a pointer button, hold it for `duration_ms`, then release it
+    pub fn click(&mut self, button: MouseButton, duration_ms: UIntValue) -> Result<()> {
+        let duration_ms = duration_ms.get()?;
+        self.button_down(button)?;
         std::thread::sleep(std::time::Duration::from_millis(duration_ms as u64));
-        self.pointer
-            .button(0, 272, wl_pointer::ButtonState::Released);
-        self.event_queue.roundtrip(&mut self.app_data)?;
+        self.button_up(button)?;
         Ok(())
     }
 
+    pub fn left_click(&mut self, duration_ms: UIntValue) -> Result<()> {
+        self.click(MouseButton::Left, duration_ms)
+    }
+
     pub fn right_click(&mut self, duration_ms: UIntValue) -> Result<()> {
-        let duration_ms = duration_ms.get()?;
+        self.click(MouseButton::Right, duration_ms)
+    }
+
+    /// Timestamp of the pointer and keyboard events, in milliseconds since the connection
+    fn time(&self) -> u32 {
+        self.start.elapsed().as_millis() as u32
+    }
+
+    /// Scroll horizontally by `dx` and vertically by `dy`, positive values scroll right and down
+    ///
+    /// Both axes are sent in the same pointer frame.
+    pub fn scroll(&mut self, dx: f64, dy: f64, mode: ScrollMode) -> Result<()> {
+        let time = self.time();
+        let axes = [
+            (wl_pointer::Axis::HorizontalScroll, dx),
+            (wl_pointer::Axis::VerticalScroll, dy),
+        ];
+        match mode {
+            ScrollMode::Wheel => {
+                self.pointer.axis_source(wl_pointer::AxisSource::Wheel);
+                for (axis, clicks) in axes {
+                    let clicks = clicks.round() as i32;
+                    if clicks != 0 {
+                        self.pointer.axis_discrete(
+                            time,
+                            axis,
+                            clicks as f64 * WHEEL_CLICK_VALUE,
+                            clicks,
+                        );
+                    }
+                }
+            }
+            ScrollMode::Smooth => {
+                self.pointer.axis_source(wl_pointer::AxisSource::Continuous);
+                for (axis, value) in axes {
+                    if value != 0. {
+                        self.pointer.axis(time, axis, value);
+                    }
+                }
+            }
+        }
+        self.pointer.frame();
+        self.roundtrip()?;
+        Ok(())
+    }
+
+    /// Touchpad-like scroll of `dx`, `dy` pixels, slowing down over `duration_ms`
+    ///
+    /// The scroll is split in one frame every 10ms with an exponentially decreasing
+    /// speed, and ends with `axis_stop` so that clients can start their own kinetic
+    /// scrolling.
+    pub fn kinetic_scroll(&mut self, dx: f64, dy: f64, duration_ms: UIntValue) -> Result<()> {
+        const FRAME_MS: u32 = 10;
+        const DECAY: f64 = 0.85;
+
+        let frames = (duration_ms.get()? / FRAME_MS).max(1);
+        let weights: Vec<f64> = (0..frames).map(|i| DECAY.powi(i as i32)).collect();
+        let total: f64 = weights.iter().sum();
+
+        for weight in weights {
+            let time = self.time();
+            self.pointer.axis_source(wl_pointer::AxisSource::Finger);
+            if dx != 0. {
+                self.pointer.axis(
+                    time,
+                    wl_pointer::Axis::HorizontalScroll,
+                    dx * weight / total,
+                );
+            }
+            if dy != 0. {
+                self.pointer
+                    .axis(time, wl_pointer::Axis::VerticalScroll, dy * weight / total);
+            }
+            self.pointer.frame();
+            self.roundtrip()?;
+            std::thread::sleep(std::time::Duration::from_millis(FRAME_MS as u64));
+        }
+
+        let time = self.time();
+        self.pointer.axis_source(wl_pointer::AxisSource::Finger);
         self.pointer
-            .button(0, 273, wl_pointer::ButtonState::Pressed);
-        self.event_queue.roundtrip(&mut self.app_data)?;
-        std::thread::sleep(std::time::Duration::from_millis(duration_ms as u64));
+            .axis_stop(time, wl_pointer::Axis::HorizontalScroll);
         self.pointer
-            .button(0, 273, wl_pointer::ButtonState::Released);
-        self.event_queue.roundtrip(&mut self.app_data)?;
+            .axis_stop(time, wl_pointer::Axis::VerticalScroll);
+        self.pointer.frame();
+        self.roundtrip()?;
+        Ok(())
+    }
+
+    /// Current state of the modifiers, as last sent to the compositor
+    pub fn modifiers(&self) -> xkb::ModifiersState {
+        self.modifiers
+    }
+
+    fn keyboard(&self) -> Result<&ZwpVirtualKeyboardV1> {
+        self.keyboard
+            .as_ref()
+            .context("the compositor didn't send a xkb keymap, keys can't be sent")
+    }
+
+    fn keymap(&self) -> Result<&xkb::Keymap> {
+        self.keymap
+            .as_ref()
+            .context("the keymap of the compositor couldn't be parsed, use keycodes instead")
+    }
+
+    /// Send a key event, followed by the new modifiers state if the key changed it
+    fn send_key(&mut self, key: u32, pressed: bool) -> Result<()> {
+        self.keyboard()?.key(self.time(), key, pressed as u32);
+
+        let previous = self.modifiers;
+        let action = self
+            .keymap
+            .as_ref()
+            .and_then(|keymap| keymap.modifier_action(key));
+        self.modifiers.update(action, pressed);
+        if self.modifiers != previous {
+            self.send_modifiers()?;
+        }
         Ok(())
     }
 
-    pub fn key_press(&mut self, key: u32, duration_ms: UIntValue) -> Result<()> {
-        self.keyboard.key(0, key, 1);
-        self.event_queue.roundtrip(&mut self.app_data)?;
+    fn send_modifiers(&self) -> Result<()> {
+        self.keyboard()?.modifiers(
+            self.modifiers.depressed,
+            self.modifiers.latched,
+            self.modifiers.locked,
+            self.modifiers.group,
+        );
+        Ok(())
+    }
+
+    fn keycodes(&self, key: KeyValue) -> Result<Vec<u32>> {
+        match key {
+            KeyValue::Code(code) => Ok(vec![code]),
+            KeyValue::Chord(chord) => self.keymap()?.chord_keycodes(&chord),
+        }
+    }
+
+    /// Press a key or a chord without releasing it
+    ///
+    /// The keys of a chord are pressed in order.
+    pub fn key_down(&mut self, key: KeyValue) -> Result<()> {
+        for key in self.keycodes(key)? {
+            self.send_key(key, true)?;
+        }
+        self.roundtrip()?;
+        Ok(())
+    }
+
+    /// Release a key or a chord
+    ///
+    /// The keys of a chord are released in reverse order.
+    pub fn key_up(&mut self, key: KeyValue) -> Result<()> {
+        for key in self.keycodes(key)?.into_iter().rev() {
+            self.send_key(key, false)?;
+        }
+        self.roundtrip()?;
+        Ok(())
+    }
+
+    /// Press a key or a chord, hold it for `duration_ms`, then release it
+    pub fn key_press(&mut self, key: KeyValue, duration_ms: UIntValue) -> Result<()> {
+        self.key_down(key.clone())?;
 
         // sleep
         let duration_ms = duration_ms.get()?;
         std::thread::sleep(std::time::Duration::from_millis(duration_ms as u64));
 
-        self.keyboard.key(0, key, 0);
-        self.event_queue.roundtrip(&mut self.app_data)?;
+        self.key_up(key)
+    }
+
+    /// Type a text, one key press per character
+    ///
+    /// Each character is looked up in the keymap of the compositor, and the key is sent
+    /// with exactly the modifiers selecting its level (according to the key type) and its
+    /// group (layout), whatever the current modifiers and locks. They are restored after
+    /// each key. Nothing is typed if a character is not in the keymap.
+    pub fn type_text(&mut self, text: &str, duration_ms: UIntValue) -> Result<()> {
+        let keymap = self.keymap()?;
+        let keys = text
+            .chars()
+            .map(|c| {
+                let key = keymap
+                    .find_char(c)
+                    .context(format!("character {c:?} is not in the keymap"))?;
+                let mask = keymap.level_modifiers(&key)?;
+                let duration = std::time::Duration::from_millis(duration_ms.get()? as u64);
+                Ok((key, mask, duration))
+            })
+            .collect::<Result<Vec<_>>>()?;
 
+        for (key, mask, duration) in keys {
+            let keyboard = self.keyboard()?;
+            keyboard.modifiers(mask, 0, 0, key.group as u32);
+            keyboard.key(self.time(), key.keycode, 1);
+            self.roundtrip()?;
+            std::thread::sleep(duration);
+            let keyboard = self.keyboard()?;
+            keyboard.key(self.time(), key.keycode, 0);
+            self.send_modifiers()?;
+            self.roundtrip()?;
+        }
         Ok(())
     }
 }
+
+impl Drop for Wdotool {
+    fn drop(&mut self) {
+        // the compositor would free them when the connection closes, but the connection
+        // may outlive this struct
+        self.app_data.release_all_shm_buffers();
+        let _ = self.event_queue.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mouse_button_from_str() {
+        let button = |name: &str| name.parse::<MouseButton>().unwrap();
+        assert_eq!(button("left"), MouseButton::Left);
+        assert_eq!(button("Middle"), MouseButton::Middle);
+        assert_eq!(button("BACK").code(), 0x116);
+        assert_eq!(button("task").code(), 0x117);
+        assert_eq!(MouseButton::Code(0x118).code(), 0x118);
+        assert!("wheel".parse::<MouseButton>().is_err());
+    }
+
+    #[test]
+    fn scroll_mode_from_str() {
+        assert_eq!("wheel".parse::<ScrollMode>().unwrap(), ScrollMode::Wheel);
+        assert_eq!("Smooth".parse::<ScrollMode>().unwrap(), ScrollMode::Smooth);
+        assert!("kinetic".parse::<ScrollMode>().is_err());
+    }
+}
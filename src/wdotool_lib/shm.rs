@@ -1,12 +1,14 @@
 use anyhow::Result;
 use libc::{ftruncate, shm_open};
+use libc::{mmap, munmap, MAP_FAILED, MAP_SHARED, PROT_READ};
 use libc::{shm_unlink, O_EXCL};
 use libc::{O_CREAT, O_RDWR, S_IRUSR, S_IWUSR};
 use log::info;
 use rand::distributions::{Alphanumeric, DistString};
-use std::ffi::CString;
+use std::ffi::{c_void, CString};
 use std::fs::File;
-use std::os::fd::FromRawFd;
+use std::os::fd::{AsRawFd, FromRawFd};
+use std::{ptr, slice};
 
 // idea of the code comes from https://wayland-book.com/surfaces/shared-memory.html
 
@@ -41,3 +43,61 @@ pub fn create_shm_file(size: usize) -> Result<File> {
     }
     anyhow::bail!("Failed to create shm file")
 }
+
+/// Read-only shared mapping of a shm file, unmapped on drop
+pub struct Mmap {
+    addr: *mut c_void,
+    len: usize,
+}
+
+// the mapping is only read, and is not tied to the thread that created it
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl Mmap {
+    pub fn new(file: &File, len: usize) -> Result<Mmap> {
+        let addr = unsafe {
+            mmap(
+                ptr::null_mut(),
+                len,
+                PROT_READ,
+                MAP_SHARED,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if addr == MAP_FAILED {
+            anyhow::bail!(
+                "failed to mmap shm file: {}",
+                std::io::Error::last_os_error()
+            );
+        }
+        Ok(Mmap { addr, len })
+    }
+
+    pub fn as_ptr(&self) -> *const u8 {
+        self.addr as *const u8
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Content of the mapping
+    ///
+    /// The compositor writes into it during a copy, so it should only be read once the
+    /// frame is ready.
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        unsafe { munmap(self.addr, self.len) };
+    }
+}
@@ -0,0 +1,1065 @@
+//! Parser for the XKB keymaps sent by the compositor in `wl_keyboard.keymap`
+//!
+//! Only the parts of the text format used by `xkbcommon` when serializing a keymap are
+//! supported: this is not a general purpose XKB compiler (no includes, no merge modes).
+
+use std::{
+    collections::HashMap,
+    os::fd::{AsRawFd, OwnedFd},
+    ptr, slice,
+};
+
+use anyhow::{Context, Result};
+use libc::{mmap, munmap, MAP_FAILED, MAP_PRIVATE, PROT_READ};
+
+use super::keysyms::{keysym_from_name, keysym_to_char};
+
+// evdev keycodes are the XKB keycodes minus 8
+const EVDEV_OFFSET: u32 = 8;
+
+// highest keycode an X11 client can receive
+const MAX_X11_KEYCODE: u32 = 255;
+
+const REAL_MODIFIERS: [&str; 8] = [
+    "Shift", "Lock", "Control", "Mod1", "Mod2", "Mod3", "Mod4", "Mod5",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    KeyName(String),
+    Str(String),
+    Punct(char),
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() || c == '\0' => {}
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '#' => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '<' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('>') => break,
+                        Some(c) => name.push(c),
+                        None => anyhow::bail!("unterminated key name <{name}"),
+                    }
+                }
+                tokens.push(Token::KeyName(name));
+            }
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            if let Some(c) = chars.next() {
+                                string.push(c);
+                            }
+                        }
+                        Some(c) => string.push(c),
+                        None => anyhow::bail!("unterminated string \"{string}"),
+                    }
+                }
+                tokens.push(Token::Str(string));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut ident = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    ident.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            c => tokens.push(Token::Punct(c)),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Split a list of tokens on `separator`, ignoring the separators nested in brackets
+fn split_tokens(tokens: &[Token], separator: char) -> Vec<&[Token]> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('{' | '[' | '(') => depth += 1,
+            Token::Punct('}' | ']' | ')') => depth -= 1,
+            Token::Punct(c) if *c == separator && depth == 0 => {
+                parts.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < tokens.len() {
+        parts.push(&tokens[start..]);
+    }
+    parts
+}
+
+/// Content of the first `{...}` or `[...]` block of the tokens
+fn block(tokens: &[Token], open: char) -> Option<&[Token]> {
+    let start = tokens.iter().position(|t| *t == Token::Punct(open))?;
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::Punct('{' | '[' | '(') => depth += 1,
+            Token::Punct('}' | ']' | ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&tokens[start + 1..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn tokens_to_string(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Ident(ident) => ident.clone(),
+            Token::KeyName(name) => format!("<{name}>"),
+            Token::Str(string) => format!("\"{string}\""),
+            Token::Punct(c) => c.to_string(),
+        })
+        .collect()
+}
+
+/// Modifier names of a `Shift+Lock` like expression
+fn parse_modifiers(tokens: &[Token]) -> Vec<String> {
+    split_tokens(tokens, '+')
+        .into_iter()
+        .filter_map(|modifier| match modifier {
+            [Token::Ident(name)] if name != "none" => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Level number of a `2` or `Level2` expression, starting at 0
+fn parse_level(tokens: &[Token]) -> Option<usize> {
+    match tokens {
+        [Token::Ident(level)] => {
+            let level = level.strip_prefix("Level").unwrap_or(level);
+            level.parse::<usize>().ok()?.checked_sub(1)
+        }
+        _ => None,
+    }
+}
+
+/// Field name, optional index and value of a `field[index]= value` statement
+type Assignment<'a> = (String, Option<&'a [Token]>, &'a [Token]);
+
+fn parse_assignment(statement: &[Token]) -> Option<Assignment<'_>> {
+    let equal = statement.iter().position(|t| *t == Token::Punct('='))?;
+    let (lhs, rhs) = (&statement[..equal], &statement[equal + 1..]);
+    let Some(Token::Ident(field)) = lhs.first() else {
+        return None;
+    };
+    Some((field.clone(), block(lhs, '['), rhs))
+}
+
+/// A key type, from the `xkb_types` section
+#[derive(Debug, Clone, Default)]
+pub struct KeyType {
+    pub name: String,
+    /// modifiers taken into account by the type
+    pub modifiers: Vec<String>,
+    /// combinations of modifiers and the level they select
+    pub map: Vec<(Vec<String>, usize)>,
+    /// modifiers not consumed by a combination
+    pub preserve: Vec<(Vec<String>, Vec<String>)>,
+    pub level_names: Vec<String>,
+}
+
+/// A symbol interpretation, from the `xkb_compatibility` section
+#[derive(Debug, Clone)]
+pub struct Interpret {
+    /// keysym name, or `Any`
+    pub keysym: String,
+    /// predicate on the modifier map of the key, like `AnyOf` or `Exactly`
+    pub predicate: String,
+    pub modifiers: Vec<String>,
+    pub virtual_modifier: Option<String>,
+    /// raw text of the action, like `SetMods(modifiers=Shift,clearLocks)`
+    pub action: Option<String>,
+}
+
+/// The symbols of a key in one group
+#[derive(Debug, Clone, Default)]
+pub struct Group {
+    /// explicit type of the group. When `None`, the type is inferred from the keysyms
+    pub key_type: Option<String>,
+    /// keysym names, one per level
+    pub levels: Vec<String>,
+}
+
+/// A key of the keymap, with its keysym names for each group and level
+#[derive(Debug, Clone)]
+pub struct Key {
+    pub name: String,
+    /// XKB keycode of the key
+    pub keycode: u32,
+    pub groups: Vec<Group>,
+    /// explicit virtual modifiers of the key
+    pub virtual_modifiers: Vec<String>,
+}
+
+impl Key {
+    /// evdev keycode of the key, as expected by `zwp_virtual_keyboard_v1.key`
+    pub fn evdev_keycode(&self) -> u32 {
+        self.keycode - EVDEV_OFFSET
+    }
+
+    /// keysym name at a given group and level, both starting at 0
+    pub fn keysym(&self, group: usize, level: usize) -> Option<&str> {
+        self.groups
+            .get(group)?
+            .levels
+            .get(level)
+            .map(|keysym| keysym.as_str())
+    }
+}
+
+/// The position of a keysym in the keymap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyLevel {
+    /// evdev keycode, as expected by `zwp_virtual_keyboard_v1.key`
+    pub keycode: u32,
+    /// group, starting at 0
+    pub group: usize,
+    /// level in the group, starting at 0
+    pub level: usize,
+}
+
+/// XKB keymap, as sent by the compositor in `wl_keyboard.keymap`
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    /// keycodes by key name, aliases included
+    keycodes: HashMap<String, u32>,
+    keys: Vec<Key>,
+    types: Vec<KeyType>,
+    interprets: Vec<Interpret>,
+    virtual_modifiers: Vec<String>,
+    /// key names bound to each real modifier
+    modifier_map: HashMap<String, Vec<String>>,
+    group_names: Vec<String>,
+}
+
+impl Keymap {
+    /// Map and parse a keymap file descriptor, as received in `wl_keyboard.keymap`
+    pub fn from_fd(fd: &OwnedFd, size: u32) -> Result<Keymap> {
+        let size = size as usize;
+        // the keymap has to be mapped privately, see wl_keyboard.keymap documentation
+        let addr = unsafe {
+            mmap(
+                ptr::null_mut(),
+                size,
+                PROT_READ,
+                MAP_PRIVATE,
+                fd.as_raw_fd(),
+                0,
+            )
+        };
+        if addr == MAP_FAILED {
+            anyhow::bail!("failed to mmap keymap: {}", std::io::Error::last_os_error());
+        }
+        let source =
+            unsafe { String::from_utf8_lossy(slice::from_raw_parts(addr as *const u8, size)) }
+                .into_owned();
+        unsafe { munmap(addr, size) };
+        Keymap::parse(&source)
+    }
+
+    pub fn parse(source: &str) -> Result<Keymap> {
+        let tokens = tokenize(source)?;
+        let content = block(&tokens, '{').context("no xkb_keymap block")?;
+
+        let mut keymap = Keymap::default();
+        for section in split_tokens(content, ';') {
+            let Some(Token::Ident(kind)) = section.first() else {
+                continue;
+            };
+            let content = block(section, '{').unwrap_or(&[]);
+            match kind.as_str() {
+                "xkb_keycodes" => keymap.parse_keycodes(content),
+                "xkb_types" => keymap.parse_types(content),
+                "xkb_compatibility" => keymap.parse_compatibility(content),
+                "xkb_symbols" => keymap.parse_symbols(content),
+                _ => {}
+            }
+        }
+        keymap.keys.sort_by_key(|key| key.keycode);
+        Ok(keymap)
+    }
+
+    fn parse_keycodes(&mut self, content: &[Token]) {
+        let mut aliases = Vec::new();
+        for statement in split_tokens(content, ';') {
+            match statement {
+                [Token::KeyName(name), Token::Punct('='), Token::Ident(value)] => {
+                    if let Ok(keycode) = value.parse() {
+                        self.keycodes.insert(name.clone(), keycode);
+                    }
+                }
+                [Token::Ident(alias), Token::KeyName(name), Token::Punct('='), Token::KeyName(target)]
+                    if alias == "alias" =>
+                {
+                    aliases.push((name.clone(), target.clone()));
+                }
+                _ => {}
+            }
+        }
+        for (name, target) in aliases {
+            if let Some(keycode) = self.keycodes.get(&target) {
+                self.keycodes.insert(name, *keycode);
+            }
+        }
+    }
+
+    fn parse_virtual_modifiers(&mut self, statement: &[Token]) {
+        for modifier in split_tokens(&statement[1..], ',') {
+            if let [Token::Ident(name), ..] = modifier {
+                if !self.virtual_modifiers.contains(name) {
+                    self.virtual_modifiers.push(name.clone());
+                }
+            }
+        }
+    }
+
+    fn parse_types(&mut self, content: &[Token]) {
+        for statement in split_tokens(content, ';') {
+            match statement {
+                [Token::Ident(kind), ..] if kind == "virtual_modifiers" => {
+                    self.parse_virtual_modifiers(statement)
+                }
+                [Token::Ident(kind), Token::Str(name), ..] if kind == "type" => {
+                    let mut key_type = KeyType {
+                        name: name.clone(),
+                        ..Default::default()
+                    };
+                    let body = block(statement, '{').unwrap_or(&[]);
+                    for field in split_tokens(body, ';') {
+                        let Some((field, index, value)) = parse_assignment(field) else {
+                            continue;
+                        };
+                        match (field.as_str(), index) {
+                            ("modifiers", None) => key_type.modifiers = parse_modifiers(value),
+                            ("map", Some(index)) => {
+                                if let Some(level) = parse_level(value) {
+                                    key_type.map.push((parse_modifiers(index), level));
+                                }
+                            }
+                            ("preserve", Some(index)) => key_type
+                                .preserve
+                                .push((parse_modifiers(index), parse_modifiers(value))),
+                            ("level_name", Some(index)) => {
+                                if let (Some(level), [Token::Str(name)]) =
+                                    (parse_level(index), value)
+                                {
+                                    if key_type.level_names.len() <= level {
+                                        key_type.level_names.resize(level + 1, String::new());
+                                    }
+                                    key_type.level_names[level] = name.clone();
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    self.types.push(key_type);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_compatibility(&mut self, content: &[Token]) {
+        for statement in split_tokens(content, ';') {
+            match statement {
+                [Token::Ident(kind), ..] if kind == "virtual_modifiers" => {
+                    self.parse_virtual_modifiers(statement)
+                }
+                [Token::Ident(kind), Token::Ident(keysym), rest @ ..] if kind == "interpret" => {
+                    let Some(body) = block(statement, '{') else {
+                        // default values, like interpret.repeat= False
+                        continue;
+                    };
+                    let (predicate, modifiers) = match rest {
+                        [Token::Punct('+'), Token::Ident(predicate), ..] => (
+                            predicate.clone(),
+                            parse_modifiers(block(rest, '(').unwrap_or(&[])),
+                        ),
+                        _ => ("AnyOfOrNone".to_string(), Vec::new()),
+                    };
+                    let mut interpret = Interpret {
+                        keysym: keysym.clone(),
+                        predicate,
+                        modifiers,
+                        virtual_modifier: None,
+                        action: None,
+                    };
+                    for field in split_tokens(body, ';') {
+                        match parse_assignment(field) {
+                            Some((field, None, [Token::Ident(modifier)]))
+                                if field == "virtualModifier" =>
+                            {
+                                interpret.virtual_modifier = Some(modifier.clone())
+                            }
+                            // only the first action is kept
+                            Some((field, None, value))
+                                if field == "action" && interpret.action.is_none() =>
+                            {
+                                interpret.action = Some(tokens_to_string(value))
+                            }
+                            _ => {}
+                        }
+                    }
+                    self.interprets.push(interpret);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_symbols(&mut self, content: &[Token]) {
+        for statement in split_tokens(content, ';') {
+            match statement {
+                [Token::Ident(kind), Token::KeyName(name), ..] if kind == "key" => {
+                    let Some(keycode) = self.keycodes.get(name) else {
+                        continue;
+                    };
+                    let key = parse_key(name, *keycode, block(statement, '{').unwrap_or(&[]));
+                    self.keys.push(key);
+                }
+                [Token::Ident(kind), Token::Ident(modifier), ..] if kind == "modifier_map" => {
+                    let keys = block(statement, '{').unwrap_or(&[]);
+                    let keys = keys.iter().filter_map(|token| match token {
+                        Token::KeyName(name) => Some(name.clone()),
+                        _ => None,
+                    });
+                    self.modifier_map
+                        .entry(modifier.clone())
+                        .or_default()
+                        .extend(keys);
+                }
+                _ => {
+                    if let Some((field, Some(index), [Token::Str(name)])) =
+                        parse_assignment(statement)
+                    {
+                        if field == "name" {
+                            if let Some(group) = parse_group(index) {
+                                if self.group_names.len() <= group {
+                                    self.group_names.resize(group + 1, String::new());
+                                }
+                                self.group_names[group] = name.clone();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    pub fn types(&self) -> &[KeyType] {
+        &self.types
+    }
+
+    pub fn interprets(&self) -> &[Interpret] {
+        &self.interprets
+    }
+
+    pub fn virtual_modifiers(&self) -> &[String] {
+        &self.virtual_modifiers
+    }
+
+    pub fn group_names(&self) -> &[String] {
+        &self.group_names
+    }
+
+    /// XKB keycode of a key name, like `AD01` or an alias like `LatQ`
+    pub fn keycode(&self, key_name: &str) -> Option<u32> {
+        self.keycodes.get(key_name).copied()
+    }
+
+    /// The key with a given XKB keycode
+    pub fn key(&self, keycode: u32) -> Option<&Key> {
+        self.keys
+            .binary_search_by_key(&keycode, |key| key.keycode)
+            .ok()
+            .map(|i| &self.keys[i])
+    }
+
+    /// The key with a given key name or alias
+    pub fn key_by_name(&self, key_name: &str) -> Option<&Key> {
+        self.key(self.keycode(key_name)?)
+    }
+
+    /// Type of a key in a group, either explicit or inferred the way xkbcommon does
+    pub fn key_type(&self, key: &Key, group: usize) -> Option<&KeyType> {
+        let group = key.groups.get(group)?;
+        let name = match &group.key_type {
+            Some(name) => name.as_str(),
+            None => infer_type(&group.levels),
+        };
+        self.types.iter().find(|key_type| key_type.name == name)
+    }
+
+    /// Mask of real modifiers of a list of real or virtual modifier names
+    pub fn modifiers_mask(&self, modifiers: &[String]) -> u32 {
+        modifiers
+            .iter()
+            .map(|modifier| self.modifier_mask(modifier).unwrap_or(0))
+            .fold(0, |mask, modifier| mask | modifier)
+    }
+
+    /// Mask of real modifiers of a modifier name
+    ///
+    /// Virtual modifiers (like `Alt` or `LevelThree`) are resolved through the keys
+    /// bound to them by the interpretations of the compatibility section.
+    pub fn modifier_mask(&self, modifier: &str) -> Option<u32> {
+        if let Some(index) = REAL_MODIFIERS.iter().position(|real| *real == modifier) {
+            return Some(1 << index);
+        }
+        if modifier == "all" {
+            return Some(0xff);
+        }
+        if !self.virtual_modifiers.iter().any(|name| name == modifier) {
+            return None;
+        }
+
+        let mut mask = 0;
+        for key in &self.keys {
+            let bound = key.virtual_modifiers.iter().any(|name| name == modifier)
+                || self.interprets.iter().any(|interpret| {
+                    interpret.virtual_modifier.as_deref() == Some(modifier)
+                        && key.keysym(0, 0) == Some(interpret.keysym.as_str())
+                });
+            if bound {
+                mask |= self.key_modifier_map(&key.name);
+            }
+        }
+        Some(mask)
+    }
+
+    /// Mask of the real modifiers a key is bound to in the modifier map
+    pub fn key_modifier_map(&self, key_name: &str) -> u32 {
+        let keycode = self.keycode(key_name);
+        let mut mask = 0;
+        for (index, real) in REAL_MODIFIERS.iter().enumerate() {
+            let Some(keys) = self.modifier_map.get(*real) else {
+                continue;
+            };
+            if keys.iter().any(|name| self.keycode(name) == keycode) {
+                mask |= 1 << index;
+            }
+        }
+        mask
+    }
+
+    /// Keys bound to a real modifier in the modifier map
+    pub fn modifier_keys(&self, modifier: &str) -> Vec<&Key> {
+        self.modifier_map
+            .get(modifier)
+            .map(|keys| {
+                keys.iter()
+                    .filter_map(|name| self.key_by_name(name))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn find(&self, predicate: impl Fn(&str) -> bool) -> Option<KeyLevel> {
+        // prefer the first group and the lowest level, so that no modifier is needed
+        // when possible
+        let max_groups = self.keys.iter().map(|key| key.groups.len()).max()?;
+        let max_levels = self
+            .keys
+            .iter()
+            .flat_map(|key| key.groups.iter())
+            .map(|group| group.levels.len())
+            .max()?;
+        // X11 keycodes are 8 bits, so keys above 255 are only a fallback: Xwayland clients
+        // never receive them
+        for x11 in [true, false] {
+            for group in 0..max_groups {
+                for level in 0..max_levels {
+                    for key in &self.keys {
+                        if (key.keycode <= MAX_X11_KEYCODE) == x11
+                            && key.keysym(group, level).is_some_and(&predicate)
+                        {
+                            return Some(KeyLevel {
+                                keycode: key.evdev_keycode(),
+                                group,
+                                level,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Find the key and level producing a keysym, given by its name
+    pub fn find_keysym(&self, keysym_name: &str) -> Option<KeyLevel> {
+        self.find(|name| name == keysym_name)
+    }
+
+    /// Find the key and level producing a character
+    pub fn find_char(&self, c: char) -> Option<KeyLevel> {
+        self.find(|name| keysym_from_name(name).and_then(keysym_to_char) == Some(c))
+    }
+
+    /// Mask of real modifiers selecting the level of a key, according to its type
+    pub fn level_modifiers(&self, key_level: &KeyLevel) -> Result<u32> {
+        if key_level.level == 0 {
+            return Ok(0);
+        }
+        let key = self
+            .key(key_level.keycode + EVDEV_OFFSET)
+            .context(format!("no key with keycode {}", key_level.keycode))?;
+        let key_type = self
+            .key_type(key, key_level.group)
+            .context(format!("no type for key <{}>", key.name))?;
+
+        // use the combination with the fewest modifiers, but never a locking one: Caps Lock
+        // or Num Lock would stay toggled after the key
+        let locks = self.modifiers_mask(&["Lock".to_string(), "NumLock".to_string()]);
+        key_type
+            .map
+            .iter()
+            .filter(|(_, level)| *level == key_level.level)
+            .map(|(modifiers, _)| (modifiers.len(), self.modifiers_mask(modifiers)))
+            .filter(|(_, mask)| mask & locks == 0)
+            .min_by_key(|(len, _)| *len)
+            .map(|(_, mask)| mask)
+            .context(format!(
+                "level {} of key <{}> is not reachable without a locking modifier",
+                key_level.level + 1,
+                key.name
+            ))
+    }
+
+    /// evdev keycodes of keys to hold to set a mask of real modifiers
+    pub fn modifiers_keycodes(&self, mask: u32) -> Result<Vec<u32>> {
+        let mut keycodes = Vec::new();
+        for (index, real) in REAL_MODIFIERS.iter().enumerate() {
+            if mask & (1 << index) == 0 {
+                continue;
+            }
+            let key = self
+                .modifier_keys(real)
+                .into_iter()
+                .next()
+                .context(format!("no key bound to modifier {real}"))?;
+            keycodes.push(key.evdev_keycode());
+        }
+        Ok(keycodes)
+    }
+
+    /// evdev keycodes of an xdotool-like chord, like `ctrl+alt+Delete` or `super+Return`
+    ///
+    /// The keycodes are in press order: the modifiers first, then the modifiers needed to
+    /// reach the level of the last key, then the last key.
+    pub fn chord_keycodes(&self, chord: &str) -> Result<Vec<u32>> {
+        let mut keycodes = Vec::new();
+        let names: Vec<&str> = chord.split('+').map(|name| name.trim()).collect();
+        let Some((last, modifiers)) = names.split_last() else {
+            anyhow::bail!("empty chord");
+        };
+
+        for modifier in modifiers {
+            let keysym = match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => "Control_L",
+                "shift" => "Shift_L",
+                "alt" => "Alt_L",
+                "super" | "logo" | "win" => "Super_L",
+                "meta" => "Meta_L",
+                "altgr" => "ISO_Level3_Shift",
+                _ => modifier,
+            };
+            let key = self
+                .find_keysym(keysym)
+                .context(format!("no key for {modifier:?} in the keymap"))?;
+            keycodes.push(key.keycode);
+        }
+
+        let mut chars = last.chars();
+        let key = match (chars.next(), chars.next()) {
+            (Some(c), None) => self.find_keysym(last).or_else(|| self.find_char(c)),
+            _ => self.find_keysym(last),
+        }
+        .context(format!("no key for {last:?} in the keymap"))?;
+        for keycode in self.modifiers_keycodes(self.level_modifiers(&key)?)? {
+            if !keycodes.contains(&keycode) {
+                keycodes.push(keycode);
+            }
+        }
+        keycodes.push(key.keycode);
+        Ok(keycodes)
+    }
+
+    /// Effect of a key on the modifiers state, if any
+    ///
+    /// The action comes from the first interpretation of the compatibility section
+    /// matching the first keysym of the key and its modifier map, like xkbcommon does.
+    pub fn modifier_action(&self, keycode: u32) -> Option<ModifierAction> {
+        let key = self.key(keycode + EVDEV_OFFSET)?;
+        let keysym = key.keysym(0, 0).unwrap_or("NoSymbol");
+        let modifier_map = self.key_modifier_map(&key.name);
+
+        let interpret = self.interprets.iter().find(|interpret| {
+            if interpret.keysym != keysym && interpret.keysym != "Any" {
+                return false;
+            }
+            let modifiers = self.modifiers_mask(&interpret.modifiers);
+            match interpret.predicate.as_str() {
+                "NoneOf" => modifier_map & modifiers == 0,
+                "AnyOf" => modifier_map & modifiers != 0,
+                "AllOf" => modifier_map & modifiers == modifiers,
+                "Exactly" => modifier_map == modifiers,
+                _ => true,
+            }
+        })?;
+
+        let action = interpret.action.as_deref()?;
+        let (kind, arguments) = action.split_once('(')?;
+        let mut mask = 0;
+        for argument in arguments.trim_end_matches(')').split(',') {
+            if let Some(("modifiers" | "mods", modifiers)) = argument.split_once('=') {
+                for modifier in modifiers.split('+') {
+                    mask |= match modifier {
+                        "modMapMods" => modifier_map,
+                        modifier => self.modifier_mask(modifier).unwrap_or(0),
+                    };
+                }
+            }
+        }
+        if mask == 0 {
+            return None;
+        }
+
+        match kind {
+            "SetMods" => Some(ModifierAction::Set(mask)),
+            "LatchMods" => Some(ModifierAction::Latch(mask)),
+            "LockMods" => Some(ModifierAction::Lock(mask)),
+            _ => None,
+        }
+    }
+}
+
+/// Effect of a modifier key, with its mask of real modifiers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModifierAction {
+    /// active while the key is held, like Shift
+    Set(u32),
+    /// active until the next key press, like ISO_Level3_Latch
+    Latch(u32),
+    /// toggled by each press, like Caps_Lock
+    Lock(u32),
+}
+
+/// Modifiers state, as sent in `zwp_virtual_keyboard_v1.modifiers`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ModifiersState {
+    pub depressed: u32,
+    pub latched: u32,
+    pub locked: u32,
+    pub group: u32,
+}
+
+impl ModifiersState {
+    /// Update the state after a key event
+    ///
+    /// Latched modifiers are cleared when a key that is not a modifier is released.
+    pub fn update(&mut self, action: Option<ModifierAction>, pressed: bool) {
+        match (action, pressed) {
+            (Some(ModifierAction::Set(mask)), true) => self.depressed |= mask,
+            (Some(ModifierAction::Set(mask)), false) => self.depressed &= !mask,
+            (Some(ModifierAction::Latch(mask)), true) => self.depressed |= mask,
+            (Some(ModifierAction::Latch(mask)), false) => {
+                self.depressed &= !mask;
+                self.latched |= mask;
+            }
+            (Some(ModifierAction::Lock(mask)), true) => {
+                self.depressed |= mask;
+                self.locked ^= mask;
+            }
+            (Some(ModifierAction::Lock(mask)), false) => self.depressed &= !mask,
+            (None, true) => {}
+            (None, false) => self.latched = 0,
+        }
+    }
+}
+
+/// Group number of a `Group1` expression, starting at 0
+fn parse_group(tokens: &[Token]) -> Option<usize> {
+    match tokens {
+        [Token::Ident(group)] => {
+            let group = group.strip_prefix("Group").unwrap_or(group);
+            group.parse::<usize>().ok()?.checked_sub(1)
+        }
+        _ => None,
+    }
+}
+
+fn parse_key(name: &str, keycode: u32, body: &[Token]) -> Key {
+    let mut key = Key {
+        name: name.to_string(),
+        keycode,
+        groups: Vec::new(),
+        virtual_modifiers: Vec::new(),
+    };
+    let mut next_group = 0;
+    for entry in split_tokens(body, ',') {
+        // a bare [...] is the symbols of the next group
+        let (field, index, value) = match entry.first() {
+            Some(Token::Punct('[')) => ("symbols".to_string(), None, entry),
+            _ => match parse_assignment(entry) {
+                Some(assignment) => assignment,
+                None => continue,
+            },
+        };
+        let group = match index {
+            Some(index) => parse_group(index),
+            None => Some(next_group),
+        };
+
+        match (field.as_str(), group) {
+            ("symbols", Some(group)) => {
+                let levels = block(value, '[').unwrap_or(&[]);
+                let levels = split_tokens(levels, ',')
+                    .into_iter()
+                    .map(|level| match level {
+                        [Token::Ident(keysym)] => keysym.clone(),
+                        _ => "NoSymbol".to_string(),
+                    })
+                    .collect();
+                if key.groups.len() <= group {
+                    key.groups.resize(group + 1, Group::default());
+                }
+                key.groups[group].levels = levels;
+                next_group = group + 1;
+            }
+            ("type", group) => {
+                let [Token::Str(type_name)] = value else {
+                    continue;
+                };
+                // a type without group index applies to all groups
+                let groups = match (index, group) {
+                    (Some(_), Some(group)) => group..group + 1,
+                    _ => 0..key.groups.len().max(1),
+                };
+                if key.groups.len() < groups.end {
+                    key.groups.resize(groups.end, Group::default());
+                }
+                for group in groups {
+                    key.groups[group].key_type = Some(type_name.clone());
+                }
+            }
+            ("virtualMods" | "virtualmodifiers", _) => {
+                key.virtual_modifiers = parse_modifiers(value);
+            }
+            _ => {}
+        }
+    }
+    key
+}
+
+fn is_keypad(keysym: &str) -> bool {
+    keysym.starts_with("KP_")
+}
+
+/// Whether two keysyms are the lower and upper case of the same letter
+fn is_case_pair(lower: &str, upper: &str) -> bool {
+    let lower = keysym_from_name(lower).and_then(keysym_to_char);
+    let upper = keysym_from_name(upper).and_then(keysym_to_char);
+    match (lower, upper) {
+        (Some(lower), Some(upper)) => {
+            lower.is_lowercase() && upper.is_uppercase() && lower.to_uppercase().eq([upper])
+        }
+        _ => false,
+    }
+}
+
+/// Name of the type of a group without explicit type, following xkbcommon rules
+fn infer_type(levels: &[String]) -> &'static str {
+    match levels {
+        [] | [_] => "ONE_LEVEL",
+        [first, second] => {
+            if is_case_pair(first, second) {
+                "ALPHABETIC"
+            } else if is_keypad(first) || is_keypad(second) {
+                "KEYPAD"
+            } else {
+                "TWO_LEVEL"
+            }
+        }
+        [first, second, third, rest @ ..] => {
+            let fourth = rest
+                .first()
+                .map(|keysym| keysym.as_str())
+                .unwrap_or("NoSymbol");
+            if is_case_pair(first, second) {
+                if is_case_pair(third, fourth) {
+                    "FOUR_LEVEL_ALPHABETIC"
+                } else {
+                    "FOUR_LEVEL_SEMIALPHABETIC"
+                }
+            } else if is_keypad(first) || is_keypad(second) {
+                "FOUR_LEVEL_KEYPAD"
+            } else {
+                "FOUR_LEVEL"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // French layout dumped from a compositor, with Caps Lock and Escape swapped
+    fn keymap() -> Keymap {
+        Keymap::parse(include_str!("../../wdotool_keymap")).unwrap()
+    }
+
+    #[test]
+    fn keycode_name_keysym() {
+        let keymap = keymap();
+        assert_eq!(keymap.keycode("AE10"), Some(19));
+        let key = keymap.key(19).unwrap();
+        assert_eq!(key.name, "AE10");
+        assert_eq!(key.evdev_keycode(), 11);
+        assert_eq!(key.keysym(0, 0), Some("agrave"));
+        assert_eq!(key.keysym(0, 2), Some("at"));
+        assert_eq!(
+            keymap.key_by_name("ESC").unwrap().keysym(0, 0),
+            Some("Caps_Lock")
+        );
+    }
+
+    #[test]
+    fn find_char_with_level3() {
+        let keymap = keymap();
+        let key_level = keymap.find_char('@').unwrap();
+        assert_eq!(
+            key_level,
+            KeyLevel {
+                keycode: 11,
+                group: 0,
+                level: 2
+            }
+        );
+        assert_eq!(keymap.level_modifiers(&key_level).unwrap(), 0x80); // Mod5
+    }
+
+    #[test]
+    fn level_modifiers_without_locks() {
+        let keymap = keymap();
+        // '1' is the second level of <AE01>, with Shift
+        let one = keymap.find_char('1').unwrap();
+        assert_eq!(keymap.level_modifiers(&one).unwrap(), 0x01);
+        // KP_1 is only reachable with NumLock, which would stay locked after the key
+        let kp_1 = keymap.find_keysym("KP_1").unwrap();
+        assert!(keymap.level_modifiers(&kp_1).is_err());
+    }
+
+    #[test]
+    fn find_char_prefers_x11_keycodes() {
+        let keymap = keymap();
+        // EuroSign is on <I443> level 1 too, but X11 clients can't receive keycode 443
+        assert_eq!(
+            keymap.find_char('€').unwrap(),
+            KeyLevel {
+                keycode: 18,
+                group: 0,
+                level: 2
+            }
+        );
+    }
+
+    #[test]
+    fn chord() {
+        let keymap = keymap();
+        assert_eq!(
+            keymap.chord_keycodes("ctrl+alt+Delete").unwrap(),
+            vec![29, 56, 111]
+        );
+        assert!(keymap.chord_keycodes("ctrl+not_a_key").is_err());
+    }
+
+    #[test]
+    fn modifier_actions() {
+        let keymap = keymap();
+        // Shift_L
+        assert_eq!(keymap.modifier_action(42), Some(ModifierAction::Set(0x01)));
+        // Caps_Lock is on <ESC>
+        assert_eq!(keymap.modifier_action(1), Some(ModifierAction::Lock(0x02)));
+        // Escape is on <CAPS>, and isn't a modifier
+        assert_eq!(keymap.modifier_action(58), None);
+        // a
+        assert_eq!(keymap.modifier_action(16), None);
+    }
+
+    #[test]
+    fn modifiers_state() {
+        let mut state = ModifiersState::default();
+        let shift = Some(ModifierAction::Set(0x01));
+        let caps_lock = Some(ModifierAction::Lock(0x02));
+        let level3_latch = Some(ModifierAction::Latch(0x80));
+
+        state.update(shift, true);
+        assert_eq!(state.depressed, 0x01);
+        state.update(shift, false);
+        assert_eq!(state, ModifiersState::default());
+
+        // locks toggle on press
+        state.update(caps_lock, true);
+        state.update(caps_lock, false);
+        assert_eq!((state.depressed, state.locked), (0, 0x02));
+        state.update(caps_lock, true);
+        state.update(caps_lock, false);
+        assert_eq!(state.locked, 0);
+
+        // latches last until the next key that isn't a modifier is released
+        state.update(level3_latch, true);
+        state.update(level3_latch, false);
+        assert_eq!((state.depressed, state.latched), (0, 0x80));
+        state.update(shift, true);
+        state.update(shift, false);
+        assert_eq!(state.latched, 0x80);
+        state.update(None, true);
+        assert_eq!(state.latched, 0x80);
+        state.update(None, false);
+        assert_eq!(state.latched, 0);
+    }
+}